@@ -1,9 +1,11 @@
 use crate::rpc_data::{PrioFeesStats, PrioFeesUpdateMessage, TxAggregateStats};
 use crate::stats_calculation::calculate_supp_percentiles;
 use log::{error, info, trace, warn};
+use solana_lite_rpc_core::structures::produced_block::ProducedBlock;
 use solana_lite_rpc_core::types::BlockStream;
 use solana_sdk::clock::Slot;
-use std::collections::BTreeMap;
+use solana_sdk::pubkey::Pubkey;
+use std::collections::{BTreeMap, HashMap};
 use std::sync::Arc;
 use tokio::sync::broadcast::error::RecvError::{Closed, Lagged};
 use tokio::sync::broadcast::Sender;
@@ -12,12 +14,58 @@ use tokio::task::JoinHandle;
 
 // note: ATM only the latest slot (highest key) is used
 const SLOTS_TO_RETAIN: u64 = 100;
+// same window as the prioritization fee cache of solana
+const ACCOUNT_FEES_SLOTS_TO_RETAIN: u64 = 150;
+
+/// minimum prioritization fees of one block used to serve getRecentPrioritizationFees
+#[derive(Clone, Debug, Default)]
+struct SlotPrioFees {
+    // minimum over all non-vote transactions
+    min_transaction_fee: Option<u64>,
+    // minimum over the non-vote transactions write-locking the account
+    min_writable_account_fees: HashMap<Pubkey, u64>,
+}
+
+impl SlotPrioFees {
+    fn from_block(block: &ProducedBlock) -> Self {
+        let mut slot_priofees = SlotPrioFees::default();
+        for tx in block.transactions.iter().filter(|tx| !tx.is_vote) {
+            let fee = tx.prioritization_fees.unwrap_or_default();
+            slot_priofees.min_transaction_fee = Some(
+                slot_priofees
+                    .min_transaction_fee
+                    .map_or(fee, |min_fee| min_fee.min(fee)),
+            );
+            for account in &tx.writable_accounts {
+                slot_priofees
+                    .min_writable_account_fees
+                    .entry(*account)
+                    .and_modify(|min_fee| *min_fee = (*min_fee).min(fee))
+                    .or_insert(fee);
+            }
+        }
+        slot_priofees
+    }
+
+    // same as solana: the block minimum raised to the highest minimum of the given accounts
+    fn get_prioritization_fee(&self, accounts: &[Pubkey]) -> u64 {
+        accounts
+            .iter()
+            .filter_map(|account| self.min_writable_account_fees.get(account))
+            .fold(
+                self.min_transaction_fee.unwrap_or_default(),
+                |fee, account_fee| fee.max(*account_fee),
+            )
+    }
+}
 
 /// put everything required to serve sync data calls here
 #[derive(Clone)]
 pub struct PrioFeeStore {
     // store priofees stats for recently processed blocks up to CLEANUP_SLOTS_AFTER
     recent: Arc<RwLock<BTreeMap<Slot, PrioFeesStats>>>,
+    // minimum fees per account for the last ACCOUNT_FEES_SLOTS_TO_RETAIN slots
+    recent_account_fees: Arc<RwLock<BTreeMap<Slot, SlotPrioFees>>>,
 }
 
 pub struct PrioFeesService {
@@ -32,14 +80,27 @@ impl PrioFeesService {
         let latest_in_store = lock.last_key_value();
         latest_in_store.map(|x| (*x.0, x.1.clone()))
     }
+
+    /// (slot, prioritization fee) for the recent slots in ascending order, see getRecentPrioritizationFees
+    pub async fn get_recent_prioritization_fees(&self, accounts: &[Pubkey]) -> Vec<(Slot, u64)> {
+        self.block_fees_store
+            .recent_account_fees
+            .read()
+            .await
+            .iter()
+            .map(|(slot, slot_priofees)| (*slot, slot_priofees.get_prioritization_fee(accounts)))
+            .collect()
+    }
 }
 
 pub async fn start_block_priofees_task(
     mut block_stream: BlockStream,
 ) -> (JoinHandle<()>, PrioFeesService) {
     let recent_data = Arc::new(RwLock::new(BTreeMap::new()));
+    let recent_account_fees = Arc::new(RwLock::new(BTreeMap::new()));
     let store = PrioFeeStore {
         recent: recent_data.clone(),
+        recent_account_fees: recent_account_fees.clone(),
     };
     let (priofees_update_sender, _priofees_update_receiver) = tokio::sync::broadcast::channel(64);
     let sender_to_return = priofees_update_sender.clone();
@@ -116,6 +177,13 @@ pub async fn start_block_priofees_task(
                         let mut lock = recent_data.write().await;
                         lock.insert(processed_slot, priofees_stats.clone());
                     }
+                    {
+                        let mut lock = recent_account_fees.write().await;
+                        lock.retain(|slot, _| {
+                            *slot > processed_slot.saturating_sub(ACCOUNT_FEES_SLOTS_TO_RETAIN)
+                        });
+                        lock.insert(processed_slot, SlotPrioFees::from_block(&block));
+                    }
                     let msg = PrioFeesUpdateMessage {
                         slot: processed_slot,
                         priofees_stats,
@@ -155,3 +223,55 @@ pub async fn start_block_priofees_task(
         },
     )
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use solana_lite_rpc_core::structures::produced_block::TransactionInfo;
+    use solana_sdk::commitment_config::CommitmentConfig;
+
+    fn create_tx(fee: u64, is_vote: bool, writable_accounts: Vec<Pubkey>) -> TransactionInfo {
+        TransactionInfo {
+            is_vote,
+            prioritization_fees: Some(fee),
+            writable_accounts,
+            ..TransactionInfo::new_for_test()
+        }
+    }
+
+    #[test]
+    fn prioritization_fee_per_account() {
+        let (hot_account, cold_account, other_account) = (
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+        );
+        let block = ProducedBlock {
+            transactions: vec![
+                create_tx(0, true, vec![hot_account]),
+                create_tx(100, false, vec![cold_account]),
+                create_tx(5000, false, vec![hot_account]),
+                create_tx(3000, false, vec![hot_account, cold_account]),
+            ],
+            leader_id: None,
+            blockhash: String::new(),
+            block_height: 1,
+            slot: 1,
+            parent_slot: 0,
+            block_time: 0,
+            commitment_config: CommitmentConfig::processed(),
+            previous_blockhash: String::new(),
+            rewards: None,
+        };
+
+        let slot_priofees = SlotPrioFees::from_block(&block);
+
+        assert_eq!(slot_priofees.get_prioritization_fee(&[]), 100);
+        assert_eq!(slot_priofees.get_prioritization_fee(&[other_account]), 100);
+        assert_eq!(slot_priofees.get_prioritization_fee(&[cold_account]), 100);
+        assert_eq!(
+            slot_priofees.get_prioritization_fee(&[cold_account, hot_account]),
+            3000
+        );
+    }
+}
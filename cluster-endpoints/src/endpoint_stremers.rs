@@ -1,7 +1,11 @@
-use solana_lite_rpc_core::types::{BlockStream, ClusterInfoStream, SlotStream, VoteAccountStream};
+use solana_lite_rpc_core::types::{
+    AccountStream, BlockStream, ClusterInfoStream, SlotStream, VoteAccountStream,
+};
 pub struct EndpointStreaming {
     pub blocks_notifier: BlockStream,
     pub slot_notifier: SlotStream,
     pub vote_account_notifier: VoteAccountStream,
     pub cluster_info_notifier: ClusterInfoStream,
+    // only available with geyser
+    pub account_notifier: Option<AccountStream>,
 }
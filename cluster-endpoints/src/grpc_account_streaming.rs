@@ -0,0 +1,244 @@
+use std::collections::{BTreeMap, HashMap};
+use std::time::Duration;
+
+use anyhow::Context;
+use futures::{SinkExt, StreamExt};
+use geyser_grpc_connector::grpc_subscription_autoreconnect::GrpcSourceConfig;
+use log::{debug, info, trace, warn};
+use solana_lite_rpc_core::stores::account_filter_store::AccountFilters;
+use solana_lite_rpc_core::structures::account_data::{AccountData, AccountNotificationMessage};
+use solana_lite_rpc_core::types::{AccountStream, SlotStream};
+use solana_lite_rpc_core::AnyhowJoinHandle;
+use solana_sdk::account::{Account, AccountSharedData};
+use solana_sdk::commitment_config::CommitmentConfig;
+use solana_sdk::pubkey::Pubkey;
+use solana_sdk::slot_history::Slot;
+use tokio::sync::broadcast::error::RecvError;
+use tokio::sync::{mpsc, watch};
+use yellowstone_grpc_client::GeyserGrpcClient;
+use yellowstone_grpc_proto::geyser::subscribe_update::UpdateOneof;
+use yellowstone_grpc_proto::geyser::{
+    CommitmentLevel, SubscribeRequest, SubscribeRequestFilterAccounts, SubscribeUpdateAccount,
+};
+
+const ACCOUNT_STREAM_CAPACITY: usize = 10_000;
+// confirmed updates waiting for their slot to be rooted
+const MAX_CONFIRMED_SLOTS_TO_BUFFER: usize = 1024;
+
+fn build_subscribe_request(
+    filters: &AccountFilters,
+    commitment_level: CommitmentLevel,
+) -> SubscribeRequest {
+    let mut accounts = HashMap::new();
+    // note: a filter without accounts and owners would match every account
+    if !filters.accounts.is_empty() {
+        accounts.insert(
+            "accounts".to_string(),
+            SubscribeRequestFilterAccounts {
+                account: filters.accounts.iter().map(Pubkey::to_string).collect(),
+                owner: vec![],
+                filters: vec![],
+            },
+        );
+    }
+    if !filters.owners.is_empty() {
+        accounts.insert(
+            "owners".to_string(),
+            SubscribeRequestFilterAccounts {
+                account: vec![],
+                owner: filters.owners.iter().map(Pubkey::to_string).collect(),
+                filters: vec![],
+            },
+        );
+    }
+
+    SubscribeRequest {
+        accounts,
+        commitment: Some(commitment_level as i32),
+        ..Default::default()
+    }
+}
+
+fn from_grpc_account_update(update: SubscribeUpdateAccount) -> Option<AccountData> {
+    let account = update.account?;
+    let pubkey = Pubkey::try_from(account.pubkey).ok()?;
+    let owner = Pubkey::try_from(account.owner).ok()?;
+
+    Some(AccountData {
+        pubkey,
+        account: AccountSharedData::from(Account {
+            lamports: account.lamports,
+            data: account.data,
+            owner,
+            executable: account.executable,
+            rent_epoch: account.rent_epoch,
+        }),
+        updated_slot: update.slot,
+        write_version: account.write_version,
+    })
+}
+
+/// stream the accounts matching the filters; the geyser subscription is updated when the filters change
+fn create_account_stream_task(
+    grpc_addr: String,
+    grpc_x_token: Option<String>,
+    commitment_config: CommitmentConfig,
+    mut account_filters: watch::Receiver<AccountFilters>,
+    account_sx: mpsc::UnboundedSender<AccountNotificationMessage>,
+) -> AnyhowJoinHandle {
+    let commitment_level = if commitment_config.is_processed() {
+        CommitmentLevel::Processed
+    } else {
+        CommitmentLevel::Confirmed
+    };
+
+    tokio::spawn(async move {
+        loop {
+            let filters = account_filters.borrow_and_update().clone();
+            if filters.is_empty() {
+                // nothing to stream until the first subscription
+                account_filters
+                    .changed()
+                    .await
+                    .context("Account filters closed")?;
+                continue;
+            }
+
+            let mut client =
+                GeyserGrpcClient::connect(grpc_addr.clone(), grpc_x_token.clone(), None)?;
+            let (mut subscribe_sx, mut stream) = match client
+                .subscribe_with_request(Some(build_subscribe_request(&filters, commitment_level)))
+                .await
+            {
+                Ok(subscription) => subscription,
+                Err(err) => {
+                    warn!("Grpc account subscription failed (retrying): {err:?}");
+                    tokio::time::sleep(Duration::from_secs(1)).await;
+                    continue;
+                }
+            };
+            debug!(
+                "Grpc account subscription ({}) to {} accounts and {} owners",
+                commitment_config.commitment,
+                filters.accounts.len(),
+                filters.owners.len()
+            );
+
+            loop {
+                tokio::select! {
+                    message = stream.next() => {
+                        let Some(Ok(message)) = message else {
+                            log::error!("Grpc account subscription broken (resubscribing)");
+                            tokio::time::sleep(Duration::from_secs(1)).await;
+                            break;
+                        };
+
+                        match message.update_oneof {
+                            Some(UpdateOneof::Account(account)) => {
+                                if let Some(data) = from_grpc_account_update(account) {
+                                    account_sx
+                                        .send(AccountNotificationMessage {
+                                            data,
+                                            commitment: commitment_config,
+                                        })
+                                        .context("Problem sending on account channel")?;
+                                }
+                            }
+                            Some(UpdateOneof::Ping(_)) => {
+                                trace!("GRPC Ping");
+                            }
+                            _ => {
+                                trace!("unknown GRPC notification");
+                            }
+                        }
+                    }
+                    changed = account_filters.changed() => {
+                        changed.context("Account filters closed")?;
+                        let filters = account_filters.borrow_and_update().clone();
+                        if filters.is_empty() {
+                            // drop the connection, see above
+                            break;
+                        }
+
+                        // the new request replaces the filters of the subscription
+                        if let Err(err) = subscribe_sx
+                            .send(build_subscribe_request(&filters, commitment_level))
+                            .await
+                        {
+                            warn!("Cannot update grpc account subscription (resubscribing): {err:?}");
+                            break;
+                        }
+                    }
+                }
+            }
+        }
+    })
+}
+
+/// processed and confirmed account updates from geyser; confirmed updates are emitted again as finalized once their slot is rooted
+pub fn create_grpc_account_streaming(
+    grpc_source: GrpcSourceConfig,
+    account_filters: watch::Receiver<AccountFilters>,
+    mut slot_notifier: SlotStream,
+) -> (AccountStream, Vec<AnyhowJoinHandle>) {
+    info!("Setup grpc account streaming from {}", grpc_source);
+
+    let (account_sx, mut account_rx) = mpsc::unbounded_channel();
+    let mut tasks = [CommitmentConfig::processed(), CommitmentConfig::confirmed()]
+        .into_iter()
+        .map(|commitment_config| {
+            create_account_stream_task(
+                grpc_source.grpc_addr.clone(),
+                grpc_source.grpc_x_token.clone(),
+                commitment_config,
+                account_filters.clone(),
+                account_sx.clone(),
+            )
+        })
+        .collect::<Vec<_>>();
+
+    let (account_sender, account_notifier) =
+        tokio::sync::broadcast::channel(ACCOUNT_STREAM_CAPACITY);
+    let jh_finalizer: AnyhowJoinHandle = tokio::spawn(async move {
+        let mut confirmed_updates: BTreeMap<Slot, Vec<AccountData>> = BTreeMap::new();
+        loop {
+            tokio::select! {
+                message = account_rx.recv() => {
+                    let message: AccountNotificationMessage = message.context("Account streams closed")?;
+                    if message.commitment.is_confirmed() {
+                        confirmed_updates
+                            .entry(message.data.updated_slot)
+                            .or_default()
+                            .push(message.data.clone());
+                        while confirmed_updates.len() > MAX_CONFIRMED_SLOTS_TO_BUFFER {
+                            confirmed_updates.pop_first();
+                        }
+                    }
+                    account_sender.send(message)?;
+                }
+                slot_notification = slot_notifier.recv() => {
+                    let root_slot = match slot_notification {
+                        Ok(slot_notification) => slot_notification.root_slot,
+                        Err(RecvError::Lagged(lagged)) => {
+                            warn!("Account finalizer lagged {} slot notifications", lagged);
+                            continue;
+                        }
+                        Err(RecvError::Closed) => anyhow::bail!("Slot stream closed unexpectedly"),
+                    };
+
+                    let still_confirmed = confirmed_updates.split_off(&(root_slot + 1));
+                    let finalized_updates = std::mem::replace(&mut confirmed_updates, still_confirmed);
+                    for data in finalized_updates.into_values().flatten() {
+                        account_sender.send(AccountNotificationMessage {
+                            data,
+                            commitment: CommitmentConfig::finalized(),
+                        })?;
+                    }
+                }
+            }
+        }
+    });
+    tasks.push(jh_finalizer);
+
+    (account_notifier, tasks)
+}
@@ -20,7 +20,7 @@ use std::time::Duration;
 use tokio::sync::broadcast::Receiver;
 use tokio::sync::mpsc::UnboundedSender;
 use yellowstone_grpc_proto::geyser::subscribe_update::UpdateOneof;
-use yellowstone_grpc_proto::geyser::SubscribeUpdate;
+use yellowstone_grpc_proto::geyser::{CommitmentLevel, SubscribeUpdate, SubscribeUpdateSlot};
 
 struct BlockExtractor(CommitmentConfig);
 
@@ -254,10 +254,8 @@ impl FromYellowstoneExtractor for crate::grpc_multiplex::SlotExtractor {
     fn map_yellowstone_update(&self, update: SubscribeUpdate) -> Option<(Slot, Self::Target)> {
         match update.update_oneof {
             Some(UpdateOneof::Slot(update_slot_message)) => {
-                let slot = SlotNotification {
-                    estimated_processed_slot: update_slot_message.slot,
-                    processed_slot: update_slot_message.slot,
-                };
+                let mut slot = SlotNotification::default();
+                apply_slot_update(&mut slot, &update_slot_message);
                 Some((update_slot_message.slot, slot))
             }
             _ => None,
@@ -265,6 +263,32 @@ impl FromYellowstoneExtractor for crate::grpc_multiplex::SlotExtractor {
     }
 }
 
+// fold a slot status update into the notification; returns false if nothing changed
+fn apply_slot_update(notification: &mut SlotNotification, update: &SubscribeUpdateSlot) -> bool {
+    let slot = update.slot;
+    match CommitmentLevel::try_from(update.status) {
+        Ok(CommitmentLevel::Processed) if slot > notification.processed_slot => {
+            notification.processed_slot = slot;
+            notification.estimated_processed_slot = slot;
+            notification.parent_slot = update.parent;
+            true
+        }
+        Ok(CommitmentLevel::Confirmed) if slot > notification.confirmed_slot => {
+            notification.confirmed_slot = slot;
+            true
+        }
+        Ok(CommitmentLevel::Finalized) if slot > notification.root_slot => {
+            notification.root_slot = slot;
+            true
+        }
+        Ok(_) => false,
+        Err(_) => {
+            warn!("Unknown slot status {} for slot {}", update.status, slot);
+            false
+        }
+    }
+}
+
 pub fn create_grpc_multiplex_slots_subscription(
     grpc_sources: Vec<GrpcSourceConfig>,
 ) -> (Receiver<SlotNotification>, AnyhowJoinHandle) {
@@ -280,6 +304,8 @@ pub fn create_grpc_multiplex_slots_subscription(
         tokio::sync::broadcast::channel(1000);
 
     let jh = tokio::spawn(async move {
+        // the same update is received from every source
+        let mut slot_notification = SlotNotification::default();
         loop {
             let mut streams_tasks = Vec::new();
             let mut recievers = Vec::new();
@@ -302,10 +328,9 @@ pub fn create_grpc_multiplex_slots_subscription(
             .await
             {
                 if let Some(slot_update) = slot_update {
-                    multiplexed_messages_sender.send(SlotNotification {
-                        processed_slot: slot_update.slot,
-                        estimated_processed_slot: slot_update.slot,
-                    })?;
+                    if apply_slot_update(&mut slot_notification, &slot_update) {
+                        multiplexed_messages_sender.send(slot_notification.clone())?;
+                    }
                 }
             }
 
@@ -2,7 +2,7 @@ use crate::grpc_multiplex::{
     create_grpc_multiplex_blocks_subscription, create_grpc_multiplex_slots_subscription,
 };
 use crate::{
-    endpoint_stremers::EndpointStreaming,
+    endpoint_stremers::EndpointStreaming, grpc_account_streaming::create_grpc_account_streaming,
     rpc_polling::vote_accounts_and_cluster_info_polling::poll_vote_accounts_and_cluster_info,
 };
 use anyhow::Context;
@@ -12,6 +12,7 @@ use itertools::Itertools;
 use solana_client::nonblocking::rpc_client::RpcClient;
 use solana_lite_rpc_core::{
     encoding::BASE64,
+    stores::account_filter_store::AccountFilters,
     structures::produced_block::{ProducedBlock, TransactionInfo},
     AnyhowJoinHandle,
 };
@@ -24,15 +25,18 @@ use solana_sdk::{
     hash::Hash,
     instruction::CompiledInstruction,
     message::{
-        v0::{self, MessageAddressTableLookup},
+        legacy,
+        v0::{self, LoadedAddresses, MessageAddressTableLookup},
         MessageHeader, VersionedMessage,
     },
     pubkey::Pubkey,
     signature::Signature,
     transaction::TransactionError,
+    transaction_context::TransactionReturnData,
 };
-use solana_transaction_status::{Reward, RewardType};
+use solana_transaction_status::{InnerInstruction, InnerInstructions, Reward, RewardType};
 use std::{collections::HashMap, sync::Arc};
+use tokio::sync::watch;
 use yellowstone_grpc_client::GeyserGrpcClient;
 use yellowstone_grpc_proto::geyser::{SubscribeRequestFilterSlots, SubscribeUpdateSlot};
 
@@ -80,15 +84,9 @@ pub fn from_grpc_block_update(
 
             let signature = signatures[0];
             let compute_units_consumed = meta.compute_units_consumed;
-
-            let message = VersionedMessage::V0(v0::Message {
-                header: MessageHeader {
-                    num_required_signatures: header.num_required_signatures as u8,
-                    num_readonly_signed_accounts: header.num_readonly_signed_accounts as u8,
-                    num_readonly_unsigned_accounts: header.num_readonly_unsigned_accounts as u8,
-                },
-                account_keys: message
-                    .account_keys
+            let loaded_addresses = LoadedAddresses {
+                writable: meta
+                    .loaded_writable_addresses
                     .into_iter()
                     .map(|key| {
                         let bytes: [u8; 32] =
@@ -96,32 +94,116 @@ pub fn from_grpc_block_update(
                         Pubkey::new_from_array(bytes)
                     })
                     .collect(),
-                recent_blockhash: Hash::new(&message.recent_blockhash),
-                instructions: message
-                    .instructions
+                readonly: meta
+                    .loaded_readonly_addresses
                     .into_iter()
-                    .map(|ix| CompiledInstruction {
-                        program_id_index: ix.program_id_index as u8,
-                        accounts: ix.accounts,
-                        data: ix.data,
-                    })
-                    .collect(),
-                address_table_lookups: message
-                    .address_table_lookups
-                    .into_iter()
-                    .map(|table| {
-                        let bytes: [u8; 32] = table
-                            .account_key
-                            .try_into()
-                            .unwrap_or(Pubkey::default().to_bytes());
-                        MessageAddressTableLookup {
-                            account_key: Pubkey::new_from_array(bytes),
-                            writable_indexes: table.writable_indexes,
-                            readonly_indexes: table.readonly_indexes,
-                        }
+                    .map(|key| {
+                        let bytes: [u8; 32] =
+                            key.try_into().unwrap_or(Pubkey::default().to_bytes());
+                        Pubkey::new_from_array(bytes)
                     })
                     .collect(),
-            });
+            };
+
+            let inner_instructions = if meta.inner_instructions_none {
+                None
+            } else {
+                Some(
+                    meta.inner_instructions
+                        .into_iter()
+                        .map(|inner| InnerInstructions {
+                            index: inner.index as u8,
+                            instructions: inner
+                                .instructions
+                                .into_iter()
+                                .map(|ix| InnerInstruction {
+                                    instruction: CompiledInstruction {
+                                        program_id_index: ix.program_id_index as u8,
+                                        accounts: ix.accounts,
+                                        data: ix.data,
+                                    },
+                                    stack_height: ix.stack_height,
+                                })
+                                .collect(),
+                        })
+                        .collect(),
+                )
+            };
+            let log_messages = if meta.log_messages_none {
+                None
+            } else {
+                Some(meta.log_messages)
+            };
+            let return_data = if meta.return_data_none {
+                None
+            } else {
+                meta.return_data.map(|return_data| {
+                    let bytes: [u8; 32] = return_data
+                        .program_id
+                        .try_into()
+                        .unwrap_or(Pubkey::default().to_bytes());
+                    TransactionReturnData {
+                        program_id: Pubkey::new_from_array(bytes),
+                        data: return_data.data,
+                    }
+                })
+            };
+
+            let header = MessageHeader {
+                num_required_signatures: header.num_required_signatures as u8,
+                num_readonly_signed_accounts: header.num_readonly_signed_accounts as u8,
+                num_readonly_unsigned_accounts: header.num_readonly_unsigned_accounts as u8,
+            };
+            let account_keys = message
+                .account_keys
+                .into_iter()
+                .map(|key| {
+                    let bytes: [u8; 32] = key.try_into().unwrap_or(Pubkey::default().to_bytes());
+                    Pubkey::new_from_array(bytes)
+                })
+                .collect();
+            let recent_blockhash = Hash::new(&message.recent_blockhash);
+            let instructions = message
+                .instructions
+                .into_iter()
+                .map(|ix| CompiledInstruction {
+                    program_id_index: ix.program_id_index as u8,
+                    accounts: ix.accounts,
+                    data: ix.data,
+                })
+                .collect();
+
+            // keep the transaction version so that it can be encoded like solana rpc does
+            let message = if message.versioned {
+                VersionedMessage::V0(v0::Message {
+                    header,
+                    account_keys,
+                    recent_blockhash,
+                    instructions,
+                    address_table_lookups: message
+                        .address_table_lookups
+                        .into_iter()
+                        .map(|table| {
+                            let bytes: [u8; 32] = table
+                                .account_key
+                                .try_into()
+                                .unwrap_or(Pubkey::default().to_bytes());
+                            MessageAddressTableLookup {
+                                account_key: Pubkey::new_from_array(bytes),
+                                writable_indexes: table.writable_indexes,
+                                readonly_indexes: table.readonly_indexes,
+                            }
+                        })
+                        .collect(),
+                })
+            } else {
+                VersionedMessage::Legacy(legacy::Message {
+                    header,
+                    account_keys,
+                    recent_blockhash,
+                    instructions,
+                })
+            };
 
             let legacy_compute_budget: Option<(u32, Option<u64>)> =
                 message.instructions().iter().find_map(|i| {
@@ -184,6 +266,9 @@ pub fn from_grpc_block_update(
                 })
                 .or(legacy_prioritization_fees);
 
+            let writable_accounts =
+                TransactionInfo::get_writable_accounts(&message, &loaded_addresses);
+
             let is_vote_transaction = message.instructions().iter().any(|i| {
                 i.program_id(message.static_account_keys())
                     .eq(&solana_sdk::vote::program::id())
@@ -194,6 +279,7 @@ pub fn from_grpc_block_update(
 
             Some(TransactionInfo {
                 signature: signature.to_string(),
+                signatures: signatures.iter().map(|sig| sig.to_string()).collect(),
                 is_vote: is_vote_transaction,
                 err,
                 cu_requested,
@@ -201,6 +287,14 @@ pub fn from_grpc_block_update(
                 cu_consumed: compute_units_consumed,
                 recent_blockhash: message.recent_blockhash().to_string(),
                 message: BASE64.encode(message.serialize()),
+                loaded_addresses,
+                fee: meta.fee,
+                pre_balances: meta.pre_balances,
+                post_balances: meta.post_balances,
+                inner_instructions,
+                log_messages,
+                return_data,
+                writable_accounts,
             })
         })
         .collect();
@@ -328,8 +422,9 @@ pub fn create_slot_stream_task(
             let mut slots = HashMap::new();
             slots.insert(
                 "client_slot".to_string(),
+                // receive every status (processed, confirmed, finalized) of a slot
                 SubscribeRequestFilterSlots {
-                    filter_by_commitment: Some(true),
+                    filter_by_commitment: Some(false),
                 },
             );
 
@@ -381,6 +476,7 @@ pub fn create_slot_stream_task(
 pub fn create_grpc_subscription(
     rpc_client: Arc<RpcClient>,
     grpc_sources: Vec<GrpcSourceConfig>,
+    account_filters: watch::Receiver<AccountFilters>,
 ) -> anyhow::Result<(EndpointStreaming, Vec<AnyhowJoinHandle>)> {
     let (cluster_info_sx, cluster_info_notifier) = tokio::sync::broadcast::channel(10);
     let (va_sx, vote_account_notifier) = tokio::sync::broadcast::channel(10);
@@ -389,6 +485,19 @@ pub fn create_grpc_subscription(
     let (slot_multiplex_channel, jh_multiplex_slotstream) =
         create_grpc_multiplex_slots_subscription(grpc_sources.clone());
 
+    // accounts are streamed from the first source only
+    let (account_notifier, mut account_streaming_tasks) = match grpc_sources.first() {
+        Some(grpc_source) => {
+            let (account_notifier, tasks) = create_grpc_account_streaming(
+                grpc_source.clone(),
+                account_filters,
+                slot_multiplex_channel.resubscribe(),
+            );
+            (Some(account_notifier), tasks)
+        }
+        None => (None, vec![]),
+    };
+
     let (block_multiplex_channel, jh_multiplex_blockstream) =
         create_grpc_multiplex_blocks_subscription(grpc_sources);
 
@@ -400,12 +509,14 @@ pub fn create_grpc_subscription(
         slot_notifier: slot_multiplex_channel,
         cluster_info_notifier,
         vote_account_notifier,
+        account_notifier,
     };
 
-    let endpoint_tasks = vec![
+    let mut endpoint_tasks = vec![
         jh_multiplex_slotstream,
         jh_multiplex_blockstream,
         cluster_info_polling,
     ];
+    endpoint_tasks.append(&mut account_streaming_tasks);
     Ok((streamers, endpoint_tasks))
 }
@@ -39,6 +39,7 @@ pub fn create_json_rpc_polling_subscription(
         slot_notifier,
         cluster_info_notifier,
         vote_account_notifier,
+        account_notifier: None,
     };
     Ok((streamers, endpoint_tasks))
 }
@@ -1,4 +1,5 @@
 pub mod endpoint_stremers;
+pub mod grpc_account_streaming;
 pub mod grpc_inspect;
 pub mod grpc_leaders_getter;
 pub mod grpc_multiplex;
@@ -7,6 +8,7 @@ pub mod grpc_subscription;
 pub mod json_rpc_leaders_getter;
 pub mod json_rpc_subscription;
 pub mod rpc_polling;
+pub mod rpc_pool;
 
 pub use geyser_grpc_connector::grpc_subscription_autoreconnect;
 pub use yellowstone_grpc_proto::geyser::CommitmentLevel;
@@ -12,8 +12,12 @@ use solana_lite_rpc_core::{
 use solana_rpc_client_api::config::RpcBlockConfig;
 use solana_sdk::borsh0_10::try_from_slice_unchecked;
 use solana_sdk::compute_budget::ComputeBudgetInstruction;
+use solana_sdk::instruction::CompiledInstruction;
+use solana_sdk::message::v0::LoadedAddresses;
 use solana_sdk::program_utils::limited_deserialize;
+use solana_sdk::pubkey::Pubkey;
 use solana_sdk::reward_type::RewardType;
+use solana_sdk::transaction_context::TransactionReturnData;
 use solana_sdk::vote::instruction::VoteInstruction;
 use solana_sdk::{
     commitment_config::{CommitmentConfig, CommitmentLevel},
@@ -22,9 +26,10 @@ use solana_sdk::{
 };
 use solana_transaction_status::option_serializer::OptionSerializer;
 use solana_transaction_status::{
-    TransactionDetails, UiConfirmedBlock, UiTransactionEncoding, UiTransactionStatusMeta,
+    InnerInstruction, InnerInstructions, TransactionDetails, UiConfirmedBlock, UiInnerInstructions,
+    UiInstruction, UiTransactionEncoding, UiTransactionReturnData, UiTransactionStatusMeta,
 };
-use std::{sync::Arc, time::Duration};
+use std::{str::FromStr, sync::Arc, time::Duration};
 use tokio::sync::broadcast::{Receiver, Sender};
 
 pub const NUM_PARALLEL_TASKS_DEFAULT: usize = 16;
@@ -194,7 +199,14 @@ pub fn from_ui_block(
         .filter_map(|tx| {
             let Some(UiTransactionStatusMeta {
                 err,
+                fee,
+                pre_balances,
+                post_balances,
+                inner_instructions,
+                log_messages,
                 compute_units_consumed,
+                loaded_addresses,
+                return_data,
                 ..
             }) = tx.meta
             else {
@@ -210,6 +222,7 @@ pub fn from_ui_block(
             };
 
             let signature = tx.signatures[0].to_string();
+            let signatures = tx.signatures.iter().map(|sig| sig.to_string()).collect();
             let cu_consumed = match compute_units_consumed {
                 OptionSerializer::Some(cu_consumed) => Some(cu_consumed),
                 _ => None,
@@ -264,6 +277,26 @@ pub fn from_ui_block(
                 }
             };
 
+            let loaded_addresses = match loaded_addresses {
+                OptionSerializer::Some(loaded_addresses) => LoadedAddresses {
+                    writable: parse_pubkeys(&loaded_addresses.writable),
+                    readonly: parse_pubkeys(&loaded_addresses.readonly),
+                },
+                _ => LoadedAddresses::default(),
+            };
+            let inner_instructions: Option<Vec<UiInnerInstructions>> = inner_instructions.into();
+            let inner_instructions = inner_instructions.map(|inner_instructions| {
+                inner_instructions
+                    .into_iter()
+                    .map(parse_inner_instructions)
+                    .collect()
+            });
+            let return_data: Option<UiTransactionReturnData> = return_data.into();
+            let return_data = return_data.and_then(parse_return_data);
+
+            let writable_accounts =
+                TransactionInfo::get_writable_accounts(&tx.message, &loaded_addresses);
+
             let blockhash = tx.message.recent_blockhash().to_string();
             let message = BinaryEncoding::Base64.encode(tx.message.serialize());
 
@@ -277,6 +310,7 @@ pub fn from_ui_block(
 
             Some(TransactionInfo {
                 signature,
+                signatures,
                 is_vote: is_vote_transaction,
                 err,
                 cu_requested,
@@ -284,6 +318,14 @@ pub fn from_ui_block(
                 cu_consumed,
                 recent_blockhash: blockhash,
                 message,
+                loaded_addresses,
+                fee,
+                pre_balances,
+                post_balances,
+                inner_instructions,
+                log_messages: log_messages.into(),
+                return_data,
+                writable_accounts,
             })
         })
         .collect();
@@ -313,6 +355,43 @@ pub fn from_ui_block(
     }
 }
 
+fn parse_pubkeys(keys: &[String]) -> Vec<Pubkey> {
+    keys.iter()
+        .filter_map(|key| Pubkey::from_str(key).ok())
+        .collect()
+}
+
+// blocks are requested with base64 encoding so inner instructions are not parsed
+fn parse_inner_instructions(inner_instructions: UiInnerInstructions) -> InnerInstructions {
+    InnerInstructions {
+        index: inner_instructions.index,
+        instructions: inner_instructions
+            .instructions
+            .into_iter()
+            .filter_map(|instruction| match instruction {
+                UiInstruction::Compiled(compiled) => Some(InnerInstruction {
+                    instruction: CompiledInstruction {
+                        program_id_index: compiled.program_id_index,
+                        accounts: compiled.accounts,
+                        data: BinaryEncoding::Base58.decode(compiled.data).ok()?,
+                    },
+                    stack_height: compiled.stack_height,
+                }),
+                UiInstruction::Parsed(_) => None,
+            })
+            .collect(),
+    }
+}
+
+fn parse_return_data(return_data: UiTransactionReturnData) -> Option<TransactionReturnData> {
+    // return data is always base64 encoded
+    let (data, _encoding) = return_data.data;
+    Some(TransactionReturnData {
+        program_id: Pubkey::from_str(&return_data.program_id).ok()?,
+        data: BinaryEncoding::Base64.decode(data).ok()?,
+    })
+}
+
 #[inline]
 fn calc_prioritization_fees(units: u32, additional_fee: u32) -> u64 {
     (units as u64 * 1000) / additional_fee as u64
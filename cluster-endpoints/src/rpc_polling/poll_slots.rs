@@ -11,8 +11,9 @@ pub async fn poll_commitment_slots(
     rpc_client: Arc<RpcClient>,
     commitment_config: CommitmentConfig,
     slot_tx: tokio::sync::mpsc::UnboundedSender<Slot>,
+    poll_interval: Duration,
 ) -> anyhow::Result<()> {
-    let mut poll_frequency = tokio::time::interval(Duration::from_millis(50));
+    let mut poll_frequency = tokio::time::interval(poll_interval);
     let mut last_slot = 0;
     let mut errors = 0;
     loop {
@@ -33,7 +34,7 @@ pub async fn poll_commitment_slots(
                 }
             }
         }
-        // wait for next poll i.e at least 50ms
+        // wait for next poll
         poll_frequency.tick().await;
     }
 }
@@ -49,6 +50,22 @@ pub fn poll_slots(
         rpc_client.clone(),
         commitment_config,
         slot_update_sx,
+        Duration::from_millis(50),
+    ));
+    // confirmed and root slots only need to be roughly up to date
+    let (confirmed_slot_sx, mut confirmed_slot_rx) = tokio::sync::mpsc::unbounded_channel();
+    let task3 = tokio::spawn(poll_commitment_slots(
+        rpc_client.clone(),
+        CommitmentConfig::confirmed(),
+        confirmed_slot_sx,
+        AVERAGE_SLOT_CHANGE_TIME,
+    ));
+    let (root_slot_sx, mut root_slot_rx) = tokio::sync::mpsc::unbounded_channel();
+    let task4 = tokio::spawn(poll_commitment_slots(
+        rpc_client.clone(),
+        CommitmentConfig::finalized(),
+        root_slot_sx,
+        AVERAGE_SLOT_CHANGE_TIME,
     ));
     let task2 = tokio::spawn(async move {
         let slot = rpc_client
@@ -58,8 +75,17 @@ pub fn poll_slots(
 
         let mut current_slot = slot;
         let mut estimated_slot = slot;
+        let mut confirmed_slot = slot;
+        let mut root_slot = 0;
 
         loop {
+            while let Ok(slot) = confirmed_slot_rx.try_recv() {
+                confirmed_slot = confirmed_slot.max(slot);
+            }
+            while let Ok(slot) = root_slot_rx.try_recv() {
+                root_slot = root_slot.max(slot);
+            }
+
             match tokio::time::timeout(AVERAGE_SLOT_CHANGE_TIME, slot_update_rx.recv()).await {
                 Ok(Some(slot)) => {
                     // slot is latest
@@ -72,6 +98,10 @@ pub fn poll_slots(
                             .send(SlotNotification {
                                 processed_slot: current_slot,
                                 estimated_processed_slot: estimated_slot,
+                                // not provided by getSlot
+                                parent_slot: None,
+                                confirmed_slot,
+                                root_slot,
                             })
                             .context("Cannot send slot notification")?;
                     }
@@ -89,6 +119,9 @@ pub fn poll_slots(
                             .send(SlotNotification {
                                 processed_slot: current_slot,
                                 estimated_processed_slot: estimated_slot,
+                                parent_slot: None,
+                                confirmed_slot,
+                                root_slot,
                             })
                             .context("Cannot send slot notification")?;
                     }
@@ -96,5 +129,5 @@ pub fn poll_slots(
             }
         }
     });
-    Ok(vec![task1, task2])
+    Ok(vec![task1, task2, task3, task4])
 }
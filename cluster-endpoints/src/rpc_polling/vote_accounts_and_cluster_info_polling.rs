@@ -1,6 +1,7 @@
 use anyhow::Context;
 use solana_client::nonblocking::rpc_client::RpcClient;
 use solana_lite_rpc_core::AnyhowJoinHandle;
+use solana_rpc_client_api::config::RpcGetVoteAccountsConfig;
 use solana_rpc_client_api::response::{RpcContactInfo, RpcVoteAccountStatus};
 use std::{sync::Arc, time::Duration};
 use tokio::sync::broadcast::Sender;
@@ -17,7 +18,14 @@ pub fn poll_vote_accounts_and_cluster_info(
                     .send(cluster_nodes)
                     .context("Should be able to send cluster info")?;
             }
-            if let Ok(vote_accounts) = rpc_client.get_vote_accounts().await {
+            // unstaked delinquents are filtered out per request
+            if let Ok(vote_accounts) = rpc_client
+                .get_vote_accounts_with_config(RpcGetVoteAccountsConfig {
+                    keep_unstaked_delinquents: Some(true),
+                    ..Default::default()
+                })
+                .await
+            {
                 vote_account_sender
                     .send(vote_accounts)
                     .context("Should be able to send vote accounts")?;
@@ -0,0 +1,431 @@
+use std::sync::{Arc, RwLock};
+use std::time::Duration;
+
+use async_trait::async_trait;
+use log::{debug, info, warn};
+use prometheus::{opts, register_int_counter, IntCounter};
+use solana_rpc_client::http_sender::HttpSender;
+use solana_rpc_client::rpc_sender::{RpcSender, RpcTransportStats};
+use solana_rpc_client_api::client_error::{
+    Error as ClientError, ErrorKind as ClientErrorKind, Result as ClientResult,
+};
+use solana_rpc_client_api::custom_error::{
+    JSON_RPC_SERVER_ERROR_BLOCK_NOT_AVAILABLE, JSON_RPC_SERVER_ERROR_MIN_CONTEXT_SLOT_NOT_REACHED,
+    JSON_RPC_SERVER_ERROR_NODE_UNHEALTHY,
+};
+use solana_rpc_client_api::request::{RpcError, RpcRequest};
+use solana_sdk::commitment_config::CommitmentConfig;
+use solana_sdk::slot_history::Slot;
+use tokio::time::Instant;
+
+lazy_static::lazy_static! {
+    static ref RPC_FAILOVERS: IntCounter =
+        register_int_counter!(opts!("literpc_rpc_failovers", "Requests retried on another rpc endpoint")).unwrap();
+}
+
+/// an endpoint is degraded after this many failed requests in a row
+pub const MAX_CONSECUTIVE_ERRORS: u32 = 3;
+/// an endpoint is degraded when it is this many slots behind the most advanced endpoint
+pub const MAX_SLOT_LAG: u64 = 32;
+
+// each failed request in a row weighs as much as a second of latency, each slot behind as 100ms
+const ERROR_PENALTY_MS: f64 = 1000.0;
+const SLOT_LAG_PENALTY_MS: f64 = 100.0;
+// weight of the latest request in the moving average of the latency
+const LATENCY_SMOOTHING: f64 = 0.2;
+
+#[derive(Debug, Clone, Default)]
+pub struct EndpointHealth {
+    /// moving average of the latency of successful requests
+    pub latency_ms: Option<f64>,
+    pub consecutive_errors: u32,
+    pub last_slot: Option<Slot>,
+    pub slot_lag: u64,
+    /// set by the health check when the endpoint failed or lags behind
+    pub failed_health_check: bool,
+}
+
+impl EndpointHealth {
+    pub fn is_degraded(&self) -> bool {
+        self.failed_health_check || self.consecutive_errors >= MAX_CONSECUTIVE_ERRORS
+    }
+
+    /// lower is better
+    pub fn score(&self) -> f64 {
+        self.latency_ms.unwrap_or_default()
+            + self.consecutive_errors as f64 * ERROR_PENALTY_MS
+            + self.slot_lag as f64 * SLOT_LAG_PENALTY_MS
+    }
+
+    fn record_success(&mut self, latency: Duration) {
+        let latency_ms = latency.as_secs_f64() * 1000.0;
+        self.latency_ms = Some(match self.latency_ms {
+            Some(average) => average + LATENCY_SMOOTHING * (latency_ms - average),
+            None => latency_ms,
+        });
+        self.consecutive_errors = 0;
+    }
+
+    fn record_error(&mut self) {
+        self.consecutive_errors += 1;
+    }
+}
+
+struct RpcEndpoint {
+    sender: HttpSender,
+    // shared by the pools created with with_timeout
+    health: Arc<RwLock<EndpointHealth>>,
+}
+
+/// upstream rpc endpoints tried in the order of their health; a request fails over to the next endpoint
+/// when an endpoint is unreachable, rate limits, is unhealthy or is behind the requested slot
+#[derive(Clone)]
+pub struct RpcEndpointPool {
+    endpoints: Arc<Vec<RpcEndpoint>>,
+}
+
+impl RpcEndpointPool {
+    pub fn new(urls: &[String]) -> anyhow::Result<Self> {
+        anyhow::ensure!(!urls.is_empty(), "at least one rpc endpoint is required");
+        Ok(Self {
+            endpoints: Arc::new(
+                urls.iter()
+                    .map(|url| RpcEndpoint {
+                        sender: HttpSender::new(url),
+                        health: Arc::new(RwLock::new(EndpointHealth::default())),
+                    })
+                    .collect(),
+            ),
+        })
+    }
+
+    /// same endpoints and health records with another request timeout
+    pub fn with_timeout(&self, timeout: Duration) -> Self {
+        Self {
+            endpoints: Arc::new(
+                self.endpoints
+                    .iter()
+                    .map(|endpoint| RpcEndpoint {
+                        sender: HttpSender::new_with_timeout(endpoint.sender.url(), timeout),
+                        health: endpoint.health.clone(),
+                    })
+                    .collect(),
+            ),
+        }
+    }
+
+    pub fn urls(&self) -> Vec<String> {
+        self.endpoints
+            .iter()
+            .map(|endpoint| endpoint.sender.url())
+            .collect()
+    }
+
+    /// health of the endpoints in the configured order
+    pub fn health(&self) -> Vec<EndpointHealth> {
+        self.endpoints
+            .iter()
+            .map(|endpoint| endpoint.health.read().unwrap().clone())
+            .collect()
+    }
+
+    /// healthy endpoints first, by score; the configured order breaks ties
+    fn ordered_endpoints(&self) -> Vec<&RpcEndpoint> {
+        let mut endpoints = self
+            .endpoints
+            .iter()
+            .map(|endpoint| (endpoint, endpoint.health.read().unwrap().clone()))
+            .collect::<Vec<_>>();
+        endpoints.sort_by(|(_, a), (_, b)| {
+            a.is_degraded()
+                .cmp(&b.is_degraded())
+                .then(a.score().total_cmp(&b.score()))
+        });
+        endpoints
+            .into_iter()
+            .map(|(endpoint, _)| endpoint)
+            .collect()
+    }
+
+    /// polls the slot of every endpoint and marks the endpoints which failed or lag behind as degraded
+    pub async fn check_health(&self) {
+        let slots = futures::future::join_all(self.endpoints.iter().map(|endpoint| async {
+            let started = Instant::now();
+            let slot = endpoint
+                .sender
+                .send(
+                    RpcRequest::GetSlot,
+                    serde_json::json!([CommitmentConfig::confirmed()]),
+                )
+                .await
+                .map_err(anyhow::Error::from)
+                .and_then(|slot| Ok(serde_json::from_value::<Slot>(slot)?));
+            (slot, started.elapsed())
+        }))
+        .await;
+
+        let highest_slot = slots
+            .iter()
+            .filter_map(|(slot, _)| slot.as_ref().ok())
+            .max()
+            .copied()
+            .unwrap_or_default();
+        for (index, (endpoint, (slot, latency))) in self.endpoints.iter().zip(slots).enumerate() {
+            let mut health = endpoint.health.write().unwrap();
+            let was_degraded = health.is_degraded();
+            match slot {
+                Ok(slot) => {
+                    health.record_success(latency);
+                    health.last_slot = Some(slot);
+                    health.slot_lag = highest_slot - slot;
+                    health.failed_health_check = health.slot_lag > MAX_SLOT_LAG;
+                }
+                Err(err) => {
+                    health.record_error();
+                    health.failed_health_check = true;
+                    debug!("rpc endpoint #{index} failed health check: {err}");
+                }
+            }
+            if !was_degraded && health.is_degraded() {
+                warn!(
+                    "rpc endpoint #{index} degraded, {} failed requests in a row, {} slots behind",
+                    health.consecutive_errors, health.slot_lag
+                );
+            } else if was_degraded && !health.is_degraded() {
+                info!("rpc endpoint #{index} recovered");
+            }
+        }
+    }
+}
+
+// sending these twice has a side effect, e.g. a second airdrop
+fn is_idempotent(request: RpcRequest) -> bool {
+    !matches!(
+        request.to_string().as_str(),
+        "sendTransaction" | "requestAirdrop"
+    )
+}
+
+// the endpoint may have executed the request before it timed out or failed to answer
+fn may_have_executed(err: &ClientError) -> bool {
+    match err.kind() {
+        ClientErrorKind::Reqwest(err) => !err.is_connect(),
+        ClientErrorKind::RpcError(RpcError::RpcResponseError { .. }) => false,
+        _ => true,
+    }
+}
+
+// errors caused by the endpoint rather than the request
+fn should_fail_over(err: &ClientError) -> bool {
+    match err.kind() {
+        ClientErrorKind::Io(_) | ClientErrorKind::Reqwest(_) | ClientErrorKind::SerdeJson(_) => {
+            true
+        }
+        ClientErrorKind::RpcError(RpcError::RpcRequestError(_)) => true,
+        ClientErrorKind::RpcError(RpcError::RpcResponseError { code, .. }) => matches!(
+            *code,
+            JSON_RPC_SERVER_ERROR_NODE_UNHEALTHY
+                | JSON_RPC_SERVER_ERROR_MIN_CONTEXT_SLOT_NOT_REACHED
+                | JSON_RPC_SERVER_ERROR_BLOCK_NOT_AVAILABLE
+        ),
+        _ => false,
+    }
+}
+
+#[async_trait]
+impl RpcSender for RpcEndpointPool {
+    async fn send(
+        &self,
+        request: RpcRequest,
+        params: serde_json::Value,
+    ) -> ClientResult<serde_json::Value> {
+        let endpoints = self.ordered_endpoints();
+        let mut endpoints = endpoints.iter().peekable();
+        loop {
+            // there is always at least one endpoint
+            let endpoint = endpoints.next().unwrap();
+            let started = Instant::now();
+            match endpoint.sender.send(request, params.clone()).await {
+                Ok(result) => {
+                    endpoint
+                        .health
+                        .write()
+                        .unwrap()
+                        .record_success(started.elapsed());
+                    return Ok(result);
+                }
+                Err(err) if should_fail_over(&err) => {
+                    endpoint.health.write().unwrap().record_error();
+                    if endpoints.peek().is_none()
+                        || (!is_idempotent(request) && may_have_executed(&err))
+                    {
+                        return Err(err);
+                    }
+                    RPC_FAILOVERS.inc();
+                    debug!("{request} failed, failing over to the next rpc endpoint: {err}");
+                }
+                // the request itself is invalid, another endpoint would answer the same
+                Err(err) => {
+                    endpoint
+                        .health
+                        .write()
+                        .unwrap()
+                        .record_success(started.elapsed());
+                    return Err(err);
+                }
+            }
+        }
+    }
+
+    fn get_transport_stats(&self) -> RpcTransportStats {
+        self.endpoints
+            .iter()
+            .map(|endpoint| endpoint.sender.get_transport_stats())
+            .fold(RpcTransportStats::default(), |mut total, stats| {
+                total.request_count += stats.request_count;
+                total.elapsed_time += stats.elapsed_time;
+                total.rate_limited_time += stats.rate_limited_time;
+                total
+            })
+    }
+
+    /// the endpoint the next request goes to
+    fn url(&self) -> String {
+        self.ordered_endpoints()[0].sender.url()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn set_health(pool: &RpcEndpointPool, index: usize, health: EndpointHealth) {
+        *pool.endpoints[index].health.write().unwrap() = health;
+    }
+
+    #[test]
+    fn order_endpoints_by_health() {
+        let urls = ["http://a:8899", "http://b:8899", "http://c:8899"]
+            .map(str::to_string)
+            .to_vec();
+        let pool = RpcEndpointPool::new(&urls).unwrap();
+        // configured order while nothing is known
+        assert_eq!(pool.url(), "http://a:8899");
+
+        set_health(
+            &pool,
+            0,
+            EndpointHealth {
+                latency_ms: Some(300.0),
+                ..Default::default()
+            },
+        );
+        set_health(
+            &pool,
+            1,
+            EndpointHealth {
+                latency_ms: Some(50.0),
+                slot_lag: 5,
+                ..Default::default()
+            },
+        );
+        set_health(
+            &pool,
+            2,
+            EndpointHealth {
+                latency_ms: Some(10.0),
+                ..Default::default()
+            },
+        );
+        assert_eq!(pool.url(), "http://c:8899");
+
+        // degraded endpoints are only tried last, even when fast
+        set_health(
+            &pool,
+            2,
+            EndpointHealth {
+                latency_ms: Some(10.0),
+                consecutive_errors: MAX_CONSECUTIVE_ERRORS,
+                ..Default::default()
+            },
+        );
+        assert_eq!(pool.url(), "http://a:8899");
+        let ordered = pool
+            .ordered_endpoints()
+            .iter()
+            .map(|endpoint| endpoint.sender.url())
+            .collect::<Vec<_>>();
+        assert_eq!(
+            ordered,
+            vec!["http://a:8899", "http://b:8899", "http://c:8899"]
+        );
+
+        // health is shared with the pool used for long requests
+        let bootstrap_pool = pool.with_timeout(Duration::from_secs(600));
+        set_health(
+            &pool,
+            0,
+            EndpointHealth {
+                failed_health_check: true,
+                ..Default::default()
+            },
+        );
+        assert_eq!(bootstrap_pool.url(), "http://b:8899");
+    }
+
+    #[tokio::test]
+    async fn fail_over_unreachable_endpoints() {
+        // nothing listens on these ports
+        let urls = ["http://127.0.0.1:1", "http://127.0.0.1:2"]
+            .map(str::to_string)
+            .to_vec();
+        let pool = RpcEndpointPool::new(&urls).unwrap();
+        assert!(pool
+            .send(RpcRequest::GetSlot, serde_json::Value::Null)
+            .await
+            .is_err());
+        assert!(pool
+            .health()
+            .iter()
+            .all(|health| health.consecutive_errors == 1));
+
+        // nothing was sent, so even transactions fail over
+        assert!(pool
+            .send(RpcRequest::SendTransaction, serde_json::Value::Null)
+            .await
+            .is_err());
+        assert!(pool
+            .health()
+            .iter()
+            .all(|health| health.consecutive_errors == 2));
+
+        pool.check_health().await;
+        assert!(pool.health().iter().all(EndpointHealth::is_degraded));
+    }
+
+    #[tokio::test]
+    async fn no_fail_over_when_request_may_have_executed() {
+        let timed_out = ClientError::from(std::io::Error::from(std::io::ErrorKind::TimedOut));
+        assert!(should_fail_over(&timed_out) && may_have_executed(&timed_out));
+        assert!(is_idempotent(RpcRequest::GetSlot));
+        assert!(!is_idempotent(RpcRequest::SendTransaction));
+        assert!(!is_idempotent(RpcRequest::RequestAirdrop));
+        // forwarded by the passthrough
+        assert!(!is_idempotent(RpcRequest::Custom {
+            method: "requestAirdrop"
+        }));
+
+        let unhealthy = ClientError::from(RpcError::RpcResponseError {
+            code: JSON_RPC_SERVER_ERROR_NODE_UNHEALTHY,
+            message: "node is unhealthy".to_string(),
+            data: solana_rpc_client_api::request::RpcResponseErrorData::Empty,
+        });
+        assert!(should_fail_over(&unhealthy) && !may_have_executed(&unhealthy));
+
+        let refused = HttpSender::new("http://127.0.0.1:1")
+            .send(RpcRequest::GetSlot, serde_json::Value::Null)
+            .await
+            .unwrap_err();
+        assert!(!may_have_executed(&refused));
+    }
+}
@@ -0,0 +1,151 @@
+use std::collections::{BTreeSet, HashMap};
+use std::sync::{Arc, Mutex};
+
+use solana_sdk::pubkey::Pubkey;
+use tokio::sync::watch;
+
+/// accounts and programs (account owners) streamed from geyser
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct AccountFilters {
+    pub accounts: BTreeSet<Pubkey>,
+    pub owners: BTreeSet<Pubkey>,
+}
+
+impl AccountFilters {
+    pub fn is_empty(&self) -> bool {
+        self.accounts.is_empty() && self.owners.is_empty()
+    }
+}
+
+#[derive(Debug, Default)]
+struct SubscriptionCounts {
+    accounts: HashMap<Pubkey, usize>,
+    owners: HashMap<Pubkey, usize>,
+}
+
+impl SubscriptionCounts {
+    fn to_filters(&self) -> AccountFilters {
+        AccountFilters {
+            accounts: self.accounts.keys().copied().collect(),
+            owners: self.owners.keys().copied().collect(),
+        }
+    }
+}
+
+fn increment(counts: &mut HashMap<Pubkey, usize>, pubkey: Pubkey) -> bool {
+    let count = counts.entry(pubkey).or_default();
+    *count += 1;
+    *count == 1
+}
+
+fn decrement(counts: &mut HashMap<Pubkey, usize>, pubkey: &Pubkey) -> bool {
+    let Some(count) = counts.get_mut(pubkey) else {
+        return false;
+    };
+    *count -= 1;
+    if *count == 0 {
+        counts.remove(pubkey);
+        return true;
+    }
+    false
+}
+
+/// reference counted subscriptions of the account stream; the resulting filters are published on every change
+#[derive(Debug, Clone)]
+pub struct AccountFilterStore {
+    counts: Arc<Mutex<SubscriptionCounts>>,
+    filters_sender: Arc<watch::Sender<AccountFilters>>,
+}
+
+impl AccountFilterStore {
+    pub fn new() -> Self {
+        let (filters_sender, _) = watch::channel(AccountFilters::default());
+        Self {
+            counts: Arc::new(Mutex::new(SubscriptionCounts::default())),
+            filters_sender: Arc::new(filters_sender),
+        }
+    }
+
+    /// latest filters, updated whenever an account or program is added or removed
+    pub fn watch(&self) -> watch::Receiver<AccountFilters> {
+        self.filters_sender.subscribe()
+    }
+
+    pub fn get_filters(&self) -> AccountFilters {
+        self.filters_sender.borrow().clone()
+    }
+
+    /// true if the updates of the account are currently streamed
+    pub fn is_streamed(&self, pubkey: &Pubkey, owner: &Pubkey) -> bool {
+        let counts = self.counts.lock().unwrap();
+        counts.accounts.contains_key(pubkey) || counts.owners.contains_key(owner)
+    }
+
+    pub fn subscribe_account(&self, pubkey: Pubkey) {
+        let mut counts = self.counts.lock().unwrap();
+        if increment(&mut counts.accounts, pubkey) {
+            self.filters_sender.send_replace(counts.to_filters());
+        }
+    }
+
+    pub fn unsubscribe_account(&self, pubkey: &Pubkey) {
+        let mut counts = self.counts.lock().unwrap();
+        if decrement(&mut counts.accounts, pubkey) {
+            self.filters_sender.send_replace(counts.to_filters());
+        }
+    }
+
+    pub fn subscribe_program(&self, program_id: Pubkey) {
+        let mut counts = self.counts.lock().unwrap();
+        if increment(&mut counts.owners, program_id) {
+            self.filters_sender.send_replace(counts.to_filters());
+        }
+    }
+
+    pub fn unsubscribe_program(&self, program_id: &Pubkey) {
+        let mut counts = self.counts.lock().unwrap();
+        if decrement(&mut counts.owners, program_id) {
+            self.filters_sender.send_replace(counts.to_filters());
+        }
+    }
+}
+
+impl Default for AccountFilterStore {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn update_filters_on_first_subscribe_and_last_unsubscribe() {
+        let store = AccountFilterStore::new();
+        let mut filters = store.watch();
+        let (account, program) = (Pubkey::new_unique(), Pubkey::new_unique());
+
+        store.subscribe_account(account);
+        store.subscribe_account(account);
+        store.subscribe_program(program);
+        assert!(filters.has_changed().unwrap());
+        assert_eq!(
+            *filters.borrow_and_update(),
+            AccountFilters {
+                accounts: BTreeSet::from([account]),
+                owners: BTreeSet::from([program]),
+            }
+        );
+
+        // still one subscriber left
+        store.unsubscribe_account(&account);
+        assert!(!filters.has_changed().unwrap());
+
+        store.unsubscribe_account(&account);
+        store.unsubscribe_program(&program);
+        // unknown pubkey is ignored
+        store.unsubscribe_program(&Pubkey::new_unique());
+        assert!(filters.borrow_and_update().is_empty());
+    }
+}
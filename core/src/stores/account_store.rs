@@ -0,0 +1,284 @@
+use std::collections::VecDeque;
+use std::sync::{Arc, Mutex};
+
+use dashmap::{mapref::entry::Entry, DashMap};
+use solana_sdk::account::ReadableAccount;
+use solana_sdk::commitment_config::{CommitmentConfig, CommitmentLevel};
+use solana_sdk::pubkey::Pubkey;
+
+use crate::stores::account_filter_store::AccountFilterStore;
+use crate::structures::account_data::AccountData;
+
+pub const DEFAULT_ACCOUNT_CACHE_CAPACITY: usize = 100_000;
+
+/// latest known state of an account for each commitment level
+#[derive(Debug, Default)]
+pub(crate) struct AccountVersions {
+    processed: Option<AccountData>,
+    confirmed: Option<AccountData>,
+    finalized: Option<AccountData>,
+    // subscribed by the cache after a miss, unsubscribed on eviction
+    on_demand: bool,
+}
+
+impl AccountVersions {
+    pub(crate) fn update(&mut self, data: AccountData, commitment_config: CommitmentConfig) {
+        let version = match commitment_config.commitment {
+            CommitmentLevel::Finalized => &mut self.finalized,
+            CommitmentLevel::Confirmed => &mut self.confirmed,
+            _ => &mut self.processed,
+        };
+        let is_newer = version.as_ref().map_or(true, |current| {
+            (data.updated_slot, data.write_version) >= (current.updated_slot, current.write_version)
+        });
+        if is_newer {
+            *version = Some(data);
+        }
+    }
+
+    /// a confirmed state is also the latest processed one unless a newer processed update was seen
+    pub(crate) fn get(&self, commitment_config: CommitmentConfig) -> Option<&AccountData> {
+        let candidates = match commitment_config.commitment {
+            CommitmentLevel::Finalized => vec![&self.finalized],
+            CommitmentLevel::Confirmed => vec![&self.confirmed, &self.finalized],
+            _ => vec![&self.processed, &self.confirmed, &self.finalized],
+        };
+        candidates
+            .into_iter()
+            .flatten()
+            .max_by_key(|data| (data.updated_slot, data.write_version))
+    }
+}
+
+/// bounded cache of accounts kept up to date by the account stream
+#[derive(Clone)]
+pub struct AccountStore {
+    accounts: Arc<DashMap<Pubkey, AccountVersions>>,
+    // oldest accounts are evicted first
+    insertion_order: Arc<Mutex<VecDeque<Pubkey>>>,
+    capacity: usize,
+    account_filters: AccountFilterStore,
+}
+
+impl AccountStore {
+    pub fn new(capacity: usize, account_filters: AccountFilterStore) -> Self {
+        Self {
+            accounts: Arc::new(DashMap::new()),
+            insertion_order: Arc::new(Mutex::new(VecDeque::new())),
+            capacity,
+            account_filters,
+        }
+    }
+
+    /// None if the account is unknown or its updates are not streamed anymore
+    pub fn get_account(
+        &self,
+        pubkey: &Pubkey,
+        commitment_config: CommitmentConfig,
+    ) -> Option<AccountData> {
+        let versions = self.accounts.get(pubkey)?;
+        let data = versions.get(commitment_config)?;
+        self.account_filters
+            .is_streamed(pubkey, data.account.owner())
+            .then(|| data.clone())
+    }
+
+    /// update from the account stream
+    pub fn update_account(&self, data: AccountData, commitment_config: CommitmentConfig) {
+        if !self
+            .account_filters
+            .is_streamed(&data.pubkey, data.account.owner())
+        {
+            // late update of an account we stopped streaming
+            return;
+        }
+        self.insert(data.pubkey, Some((data, commitment_config)), false);
+    }
+
+    /// streams the updates of an account missing from the cache until it is evicted; called before
+    /// the account is fetched so that no update racing with the fetch is lost
+    pub fn subscribe_on_demand(&self, pubkey: Pubkey) {
+        self.insert(pubkey, None, true);
+    }
+
+    /// account fetched after a cache miss, kept only if no newer update was streamed meanwhile
+    pub fn add_account_on_demand(&self, data: AccountData, commitment_config: CommitmentConfig) {
+        self.insert(data.pubkey, Some((data, commitment_config)), true);
+    }
+
+    pub fn len(&self) -> usize {
+        self.accounts.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.accounts.is_empty()
+    }
+
+    fn insert(
+        &self,
+        pubkey: Pubkey,
+        update: Option<(AccountData, CommitmentConfig)>,
+        on_demand: bool,
+    ) {
+        let mut insertion_order = self.insertion_order.lock().unwrap();
+        match self.accounts.entry(pubkey) {
+            Entry::Occupied(mut entry) => {
+                let versions = entry.get_mut();
+                if let Some((data, commitment_config)) = update {
+                    versions.update(data, commitment_config);
+                }
+                if on_demand && !versions.on_demand {
+                    versions.on_demand = true;
+                    self.account_filters.subscribe_account(pubkey);
+                }
+                return;
+            }
+            Entry::Vacant(entry) => {
+                let mut versions = AccountVersions {
+                    on_demand,
+                    ..Default::default()
+                };
+                if let Some((data, commitment_config)) = update {
+                    versions.update(data, commitment_config);
+                }
+                entry.insert(versions);
+            }
+        }
+        if on_demand {
+            self.account_filters.subscribe_account(pubkey);
+        }
+
+        insertion_order.push_back(pubkey);
+        while insertion_order.len() > self.capacity {
+            let Some(evicted) = insertion_order.pop_front() else {
+                break;
+            };
+            if let Some((_, versions)) = self.accounts.remove(&evicted) {
+                if versions.on_demand {
+                    self.account_filters.unsubscribe_account(&evicted);
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use solana_sdk::account::{Account, AccountSharedData};
+
+    fn account_data(pubkey: Pubkey, owner: Pubkey, slot: u64, lamports: u64) -> AccountData {
+        AccountData {
+            pubkey,
+            account: AccountSharedData::from(Account {
+                lamports,
+                data: vec![],
+                owner,
+                executable: false,
+                rent_epoch: 0,
+            }),
+            updated_slot: slot,
+            write_version: 0,
+        }
+    }
+
+    #[test]
+    fn serve_latest_account_per_commitment_and_evict() {
+        let account_filters = AccountFilterStore::default();
+        let store = AccountStore::new(2, account_filters.clone());
+        let (program, owner) = (Pubkey::new_unique(), Pubkey::new_unique());
+        account_filters.subscribe_program(program);
+
+        let pubkey = Pubkey::new_unique();
+        store.update_account(
+            account_data(pubkey, program, 10, 1),
+            CommitmentConfig::finalized(),
+        );
+        store.update_account(
+            account_data(pubkey, program, 12, 2),
+            CommitmentConfig::confirmed(),
+        );
+        // outdated
+        store.update_account(
+            account_data(pubkey, program, 11, 3),
+            CommitmentConfig::confirmed(),
+        );
+        let lamports = |commitment_config| {
+            store
+                .get_account(&pubkey, commitment_config)
+                .map(|data| data.account.lamports())
+        };
+        assert_eq!(lamports(CommitmentConfig::finalized()), Some(1));
+        assert_eq!(lamports(CommitmentConfig::confirmed()), Some(2));
+        assert_eq!(lamports(CommitmentConfig::processed()), Some(2));
+
+        // not streamed
+        let other = Pubkey::new_unique();
+        store.update_account(
+            account_data(other, owner, 12, 1),
+            CommitmentConfig::confirmed(),
+        );
+        assert!(store
+            .get_account(&other, CommitmentConfig::confirmed())
+            .is_none());
+
+        store.add_account_on_demand(
+            account_data(other, owner, 12, 1),
+            CommitmentConfig::confirmed(),
+        );
+        assert!(store
+            .get_account(&other, CommitmentConfig::confirmed())
+            .is_some());
+        assert!(account_filters.get_filters().accounts.contains(&other));
+
+        // evicts the oldest account
+        store.add_account_on_demand(
+            account_data(Pubkey::new_unique(), owner, 13, 1),
+            CommitmentConfig::confirmed(),
+        );
+        assert_eq!(store.len(), 2);
+        assert!(store
+            .get_account(&pubkey, CommitmentConfig::confirmed())
+            .is_none());
+
+        // on demand subscription is dropped with the account
+        store.add_account_on_demand(
+            account_data(Pubkey::new_unique(), owner, 13, 1),
+            CommitmentConfig::confirmed(),
+        );
+        assert!(!account_filters.get_filters().accounts.contains(&other));
+    }
+
+    #[test]
+    fn keep_updates_streamed_while_fetching() {
+        let account_filters = AccountFilterStore::default();
+        let store = AccountStore::new(1, account_filters.clone());
+        let (pubkey, owner) = (Pubkey::new_unique(), Pubkey::new_unique());
+
+        store.subscribe_on_demand(pubkey);
+        assert!(account_filters.get_filters().accounts.contains(&pubkey));
+        assert!(store
+            .get_account(&pubkey, CommitmentConfig::confirmed())
+            .is_none());
+
+        // written after the fetch read the account
+        store.update_account(
+            account_data(pubkey, owner, 12, 2),
+            CommitmentConfig::confirmed(),
+        );
+        store.add_account_on_demand(
+            account_data(pubkey, owner, 11, 1),
+            CommitmentConfig::confirmed(),
+        );
+        assert_eq!(
+            store
+                .get_account(&pubkey, CommitmentConfig::confirmed())
+                .map(|data| data.account.lamports()),
+            Some(2)
+        );
+
+        // subscribed once, so a single eviction drops the subscription
+        store.subscribe_on_demand(Pubkey::new_unique());
+        assert!(!account_filters.get_filters().accounts.contains(&pubkey));
+    }
+}
@@ -6,7 +6,7 @@ use solana_sdk::{clock::MAX_RECENT_BLOCKHASHES, slot_history::Slot};
 use std::sync::Arc;
 use tokio::sync::RwLock;
 
-use crate::structures::produced_block::ProducedBlock;
+use crate::structures::{fork_tree::ForkTreeNode, produced_block::ProducedBlock};
 
 #[derive(Clone, Debug)]
 pub struct BlockInformation {
@@ -34,6 +34,7 @@ impl BlockInformation {
 #[derive(Clone)]
 pub struct BlockInformationStore {
     blocks: Arc<DashMap<String, BlockInformation>>,
+    latest_processed_block: Arc<RwLock<BlockInformation>>,
     latest_confirmed_block: Arc<RwLock<BlockInformation>>,
     latest_finalized_block: Arc<RwLock<BlockInformation>>,
 }
@@ -48,6 +49,7 @@ impl BlockInformationStore {
         );
 
         Self {
+            latest_processed_block: Arc::new(RwLock::new(latest_finalized_block.clone())),
             latest_confirmed_block: Arc::new(RwLock::new(latest_finalized_block.clone())),
             latest_finalized_block: Arc::new(RwLock::new(latest_finalized_block)),
             blocks,
@@ -66,8 +68,10 @@ impl BlockInformationStore {
     ) -> Arc<RwLock<BlockInformation>> {
         if commitment_config.is_finalized() {
             self.latest_finalized_block.clone()
-        } else {
+        } else if commitment_config.is_confirmed() {
             self.latest_confirmed_block.clone()
+        } else {
+            self.latest_processed_block.clone()
         }
     }
 
@@ -124,14 +128,45 @@ impl BlockInformationStore {
             }
         }
 
-        // update latest block
-        let latest_block = self.get_latest_block_arc(commitment_config);
-        if slot > latest_block.read().await.slot {
-            *latest_block.write().await = block_info;
+        // update latest block of the commitment and of the lower ones, they never lag behind
+        for latest_commitment_config in [
+            CommitmentConfig::processed(),
+            CommitmentConfig::confirmed(),
+            CommitmentConfig::finalized(),
+        ] {
+            if !Self::satisfies(commitment_config, latest_commitment_config) {
+                break;
+            }
+            let latest_block = self.get_latest_block_arc(latest_commitment_config);
+            if slot > latest_block.read().await.slot {
+                *latest_block.write().await = block_info.clone();
+            }
         }
         true
     }
 
+    /// drop the processed blocks of abandoned forks, the processed tip falls back to the confirmed one
+    pub async fn remove_orphaned_blocks(&self, orphaned_blocks: &[ForkTreeNode]) {
+        for orphaned_block in orphaned_blocks {
+            self.blocks
+                .remove_if(&orphaned_block.blockhash, |_, block_info| {
+                    block_info.commitment_config.is_processed()
+                });
+        }
+
+        let latest_processed_blockhash = self
+            .get_latest_blockhash(CommitmentConfig::processed())
+            .await;
+        if orphaned_blocks
+            .iter()
+            .any(|orphaned_block| orphaned_block.blockhash == latest_processed_blockhash)
+        {
+            *self.latest_processed_block.write().await = self
+                .get_latest_block_info(CommitmentConfig::confirmed())
+                .await;
+        }
+    }
+
     pub async fn clean(&self) {
         let finalized_block_information = self
             .get_latest_block_info(CommitmentConfig::finalized())
@@ -157,11 +192,138 @@ impl BlockInformationStore {
     ) -> (bool, Slot) {
         let latest_block = self.get_latest_block(commitment_config).await;
         match self.blocks.get(blockhash) {
-            Some(block_information) => (
-                latest_block.block_height <= block_information.last_valid_blockheight,
-                latest_block.slot,
-            ),
-            None => (false, latest_block.slot),
+            Some(block_information)
+                if Self::satisfies(block_information.commitment_config, commitment_config) =>
+            {
+                (
+                    latest_block.block_height <= block_information.last_valid_blockheight,
+                    latest_block.slot,
+                )
+            }
+            _ => (false, latest_block.slot),
+        }
+    }
+
+    /// true if a block at the commitment level is also at the requested commitment level
+    fn satisfies(commitment_config: CommitmentConfig, requested: CommitmentConfig) -> bool {
+        if requested.is_finalized() {
+            commitment_config.is_finalized()
+        } else if requested.is_confirmed() {
+            commitment_config.is_at_least_confirmed()
+        } else {
+            true
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn block_info(slot: Slot, commitment_config: CommitmentConfig) -> BlockInformation {
+        BlockInformation {
+            slot,
+            block_height: slot,
+            last_valid_blockheight: slot + MAX_RECENT_BLOCKHASHES as u64,
+            cleanup_slot: slot + 1000,
+            blockhash: format!("blockhash-{slot}"),
+            commitment_config,
         }
     }
+
+    #[test]
+    fn processed_blocks() {
+        futures::executor::block_on(async {
+            let store = BlockInformationStore::new(block_info(10, CommitmentConfig::finalized()));
+
+            store
+                .add_block(block_info(11, CommitmentConfig::processed()))
+                .await;
+            store
+                .add_block(block_info(12, CommitmentConfig::processed()))
+                .await;
+            assert_eq!(
+                store
+                    .get_latest_block(CommitmentConfig::processed())
+                    .await
+                    .slot,
+                12
+            );
+            assert_eq!(
+                store
+                    .get_latest_block(CommitmentConfig::confirmed())
+                    .await
+                    .slot,
+                10
+            );
+            let blockhash = "blockhash-12".to_string();
+            assert!(
+                store
+                    .is_blockhash_valid(&blockhash, CommitmentConfig::processed())
+                    .await
+                    .0
+            );
+            assert!(
+                !store
+                    .is_blockhash_valid(&blockhash, CommitmentConfig::confirmed())
+                    .await
+                    .0
+            );
+
+            // 11 is not confirmed yet, only the fork tree tells if it was abandoned
+            store
+                .add_block(block_info(12, CommitmentConfig::confirmed()))
+                .await;
+            assert!(
+                store
+                    .is_blockhash_valid(&"blockhash-11".to_string(), CommitmentConfig::processed())
+                    .await
+                    .0
+            );
+            assert!(
+                store
+                    .is_blockhash_valid(&blockhash, CommitmentConfig::confirmed())
+                    .await
+                    .0
+            );
+
+            // the processed tip never lags behind the confirmed one
+            store
+                .add_block(block_info(14, CommitmentConfig::confirmed()))
+                .await;
+            assert_eq!(
+                store
+                    .get_latest_block(CommitmentConfig::processed())
+                    .await
+                    .slot,
+                14
+            );
+
+            // 15 was on a fork which got abandoned
+            store
+                .add_block(block_info(15, CommitmentConfig::processed()))
+                .await;
+            store
+                .remove_orphaned_blocks(&[ForkTreeNode {
+                    slot: 15,
+                    parent_slot: 13,
+                    blockhash: "blockhash-15".to_string(),
+                    commitment_config: CommitmentConfig::processed(),
+                    signatures: vec![],
+                }])
+                .await;
+            assert!(store.get_block_info("blockhash-15").is_none());
+            assert_eq!(
+                store
+                    .get_latest_block(CommitmentConfig::processed())
+                    .await
+                    .slot,
+                14
+            );
+
+            store.clean().await;
+            assert!(store.get_block_info("blockhash-11").is_some());
+            assert!(store.get_block_info("blockhash-12").is_some());
+        });
+    }
 }
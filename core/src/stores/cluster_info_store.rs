@@ -1,10 +1,26 @@
 use dashmap::DashMap;
+use itertools::Itertools;
+use log::warn;
+use serde::{Deserialize, Serialize};
 use solana_rpc_client_api::response::RpcContactInfo;
 use solana_sdk::pubkey::Pubkey;
-use std::{str::FromStr, sync::Arc};
+use std::{
+    collections::{HashMap, HashSet},
+    str::FromStr,
+    sync::Arc,
+};
 
 use crate::types::ClusterInfoStream;
 
+/// number of cluster nodes running a given software version and feature set
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ClusterVersionCount {
+    pub version: Option<String>,
+    pub feature_set: Option<u32>,
+    pub node_count: usize,
+}
+
 #[derive(Debug, Clone, Default)]
 pub struct ClusterInfo {
     pub cluster_nodes: Arc<DashMap<Pubkey, Arc<RpcContactInfo>>>,
@@ -19,11 +35,131 @@ impl ClusterInfo {
             .recv()
             .await
             .expect("Failed to recieve on broadcast channel");
+        self.update_cluster_nodes(&cluster_nodes);
+        Ok(())
+    }
+
+    /// replace the known nodes by the latest snapshot of the cluster; nodes that left gossip are evicted
+    pub fn update_cluster_nodes(&self, cluster_nodes: &[RpcContactInfo]) {
+        // an empty list is most likely a failure of the upstream rpc, keep what we have
+        if cluster_nodes.is_empty() {
+            warn!("Received empty list of cluster nodes - ignoring");
+            return;
+        }
+
+        let mut pubkeys = HashSet::with_capacity(cluster_nodes.len());
         cluster_nodes.iter().for_each(|x| {
             if let Ok(pubkey) = Pubkey::from_str(x.pubkey.as_str()) {
                 self.cluster_nodes.insert(pubkey, Arc::new(x.clone()));
+                pubkeys.insert(pubkey);
             }
         });
-        Ok(())
+
+        self.cluster_nodes
+            .retain(|pubkey, _| pubkeys.contains(pubkey));
+    }
+
+    pub fn get_cluster_nodes(&self) -> Vec<RpcContactInfo> {
+        self.cluster_nodes
+            .iter()
+            .map(|entry| entry.value().as_ref().clone())
+            .collect()
+    }
+
+    /// node count per version and feature set, most common first
+    pub fn get_version_summary(&self) -> Vec<ClusterVersionCount> {
+        let mut counts: HashMap<(Option<String>, Option<u32>), usize> = HashMap::new();
+        for entry in self.cluster_nodes.iter() {
+            let node = entry.value();
+            *counts
+                .entry((node.version.clone(), node.feature_set))
+                .or_default() += 1;
+        }
+
+        counts
+            .into_iter()
+            .map(|((version, feature_set), node_count)| ClusterVersionCount {
+                version,
+                feature_set,
+                node_count,
+            })
+            .sorted_by(|a, b| {
+                b.node_count
+                    .cmp(&a.node_count)
+                    .then_with(|| a.version.cmp(&b.version))
+                    .then_with(|| a.feature_set.cmp(&b.feature_set))
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn create_contact_info(pubkey: Pubkey, version: &str, feature_set: u32) -> RpcContactInfo {
+        RpcContactInfo {
+            pubkey: pubkey.to_string(),
+            gossip: None,
+            tpu: None,
+            tpu_quic: None,
+            rpc: None,
+            pubsub: None,
+            version: Some(version.to_string()),
+            feature_set: Some(feature_set),
+            shred_version: None,
+        }
+    }
+
+    #[test]
+    fn evict_nodes_that_left_gossip() {
+        let cluster_info = ClusterInfo::default();
+        let (node1, node2, node3) = (
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+        );
+
+        cluster_info.update_cluster_nodes(&[
+            create_contact_info(node1, "1.17.20", 1),
+            create_contact_info(node2, "1.17.20", 1),
+        ]);
+        cluster_info.update_cluster_nodes(&[
+            create_contact_info(node2, "1.17.20", 1),
+            create_contact_info(node3, "1.18.1", 2),
+        ]);
+
+        assert_eq!(cluster_info.cluster_nodes.len(), 2);
+        assert!(!cluster_info.cluster_nodes.contains_key(&node1));
+
+        // empty snapshot does not wipe the cluster
+        cluster_info.update_cluster_nodes(&[]);
+        assert_eq!(cluster_info.cluster_nodes.len(), 2);
+    }
+
+    #[test]
+    fn summarize_versions() {
+        let cluster_info = ClusterInfo::default();
+        cluster_info.update_cluster_nodes(&[
+            create_contact_info(Pubkey::new_unique(), "1.18.1", 2),
+            create_contact_info(Pubkey::new_unique(), "1.17.20", 1),
+            create_contact_info(Pubkey::new_unique(), "1.18.1", 2),
+        ]);
+
+        assert_eq!(
+            cluster_info.get_version_summary(),
+            vec![
+                ClusterVersionCount {
+                    version: Some("1.18.1".to_string()),
+                    feature_set: Some(2),
+                    node_count: 2,
+                },
+                ClusterVersionCount {
+                    version: Some("1.17.20".to_string()),
+                    feature_set: Some(1),
+                    node_count: 1,
+                },
+            ]
+        );
     }
 }
@@ -4,15 +4,23 @@ use solana_sdk::hash::Hash;
 use solana_sdk::slot_history::Slot;
 use solana_sdk::{commitment_config::CommitmentConfig, pubkey::Pubkey};
 use std::sync::{atomic::AtomicU64, Arc};
-use tokio::sync::RwLock;
+use tokio::sync::{broadcast, RwLock};
 
 use crate::{
     stores::{
-        block_information_store::BlockInformationStore, cluster_info_store::ClusterInfo,
-        subscription_store::SubscriptionStore, tx_store::TxStore,
+        account_filter_store::AccountFilterStore,
+        account_store::{AccountStore, DEFAULT_ACCOUNT_CACHE_CAPACITY},
+        block_information_store::BlockInformationStore,
+        cluster_info_store::ClusterInfo,
+        performance_samples_store::PerformanceSamplesStore,
+        program_account_store::ProgramAccountStore,
+        subscription_store::SubscriptionStore,
+        tx_store::TxStore,
+        vote_account_store::VoteAccountStore,
     },
     structures::{
         epoch::{Epoch, EpochCache},
+        fork_tree::{ForkTree, ReorgNotification},
         identity_stakes::IdentityStakes,
         slot_notification::{AtomicSlot, SlotNotification},
         transaction_sent_info::SentTransactionInfo,
@@ -39,6 +47,16 @@ pub struct DataCache {
     pub cluster_info: ClusterInfo,
     pub epoch_data: EpochCache,
     pub leader_schedule: Arc<RwLock<CalculatedSchedule>>,
+    pub performance_samples: PerformanceSamplesStore,
+    // accounts and programs streamed from geyser on demand
+    pub account_filters: AccountFilterStore,
+    pub accounts: AccountStore,
+    pub program_accounts: ProgramAccountStore,
+    pub vote_accounts: VoteAccountStore,
+    // processed and confirmed blocks above the finalized slot
+    pub fork_tree: Arc<RwLock<ForkTree>>,
+    // sent once the blocks and transactions of abandoned forks were rolled back
+    pub reorg_notifier: broadcast::Sender<ReorgNotification>,
 }
 
 impl DataCache {
@@ -72,6 +90,7 @@ impl DataCache {
     }
 
     pub fn new_for_tests() -> Self {
+        let account_filters = AccountFilterStore::default();
         Self {
             block_information_store: BlockInformationStore::new(BlockInformation {
                 block_height: 0,
@@ -90,6 +109,13 @@ impl DataCache {
             },
             epoch_data: EpochCache::new_for_tests(),
             leader_schedule: Arc::new(RwLock::new(CalculatedSchedule::default())),
+            performance_samples: PerformanceSamplesStore::default(),
+            account_filters: account_filters.clone(),
+            accounts: AccountStore::new(DEFAULT_ACCOUNT_CACHE_CAPACITY, account_filters),
+            program_accounts: ProgramAccountStore::default(),
+            vote_accounts: VoteAccountStore::default(),
+            fork_tree: Arc::new(RwLock::new(ForkTree::new(0))),
+            reorg_notifier: broadcast::channel(100).0,
         }
     }
 }
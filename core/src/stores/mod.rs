@@ -1,7 +1,12 @@
 // this mod will contain all the different stores that are used by lite-rpc
 
+pub mod account_filter_store;
+pub mod account_store;
 pub mod block_information_store;
 pub mod cluster_info_store;
 pub mod data_cache;
+pub mod performance_samples_store;
+pub mod program_account_store;
 pub mod subscription_store;
 pub mod tx_store;
+pub mod vote_account_store;
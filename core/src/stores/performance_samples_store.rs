@@ -0,0 +1,157 @@
+use std::collections::VecDeque;
+use std::sync::{Arc, Mutex};
+
+use solana_rpc_client_api::response::RpcPerfSample;
+use solana_sdk::commitment_config::CommitmentLevel;
+use solana_sdk::slot_history::Slot;
+
+use crate::structures::produced_block::ProducedBlock;
+
+// same as solana: 720 samples of 60 seconds = 12 hours
+pub const MAX_PERFORMANCE_SAMPLES: usize = 720;
+pub const PERFORMANCE_SAMPLE_PERIOD_SECS: u16 = 60;
+
+#[derive(Debug, Default)]
+struct SampleAccumulator {
+    // highest slot when the previous sample was taken
+    start_slot: Option<Slot>,
+    highest_slot: Slot,
+    num_transactions: u64,
+    num_non_vote_transactions: u64,
+}
+
+/// performance samples computed from the confirmed blocks seen by lite-rpc, newest first
+#[derive(Debug, Clone, Default)]
+pub struct PerformanceSamplesStore {
+    samples: Arc<Mutex<VecDeque<RpcPerfSample>>>,
+    accumulator: Arc<Mutex<SampleAccumulator>>,
+}
+
+impl PerformanceSamplesStore {
+    /// count the transactions of the block into the current sample; only confirmed blocks are counted
+    pub fn add_block(&self, block: &ProducedBlock) {
+        if block.commitment_config.commitment != CommitmentLevel::Confirmed {
+            return;
+        }
+
+        let num_non_vote_transactions =
+            block.transactions.iter().filter(|tx| !tx.is_vote).count() as u64;
+
+        let mut accumulator = self.accumulator.lock().unwrap();
+        if accumulator.start_slot.is_none() {
+            accumulator.start_slot = Some(block.slot.saturating_sub(1));
+        }
+        accumulator.highest_slot = accumulator.highest_slot.max(block.slot);
+        accumulator.num_transactions += block.transactions.len() as u64;
+        accumulator.num_non_vote_transactions += num_non_vote_transactions;
+    }
+
+    /// close the current sample; to be called every sample period
+    pub fn take_sample(&self, sample_period_secs: u16) {
+        let sample = {
+            let mut accumulator = self.accumulator.lock().unwrap();
+            let Some(start_slot) = accumulator.start_slot else {
+                // no block seen yet
+                return;
+            };
+
+            let sample = RpcPerfSample {
+                slot: accumulator.highest_slot,
+                num_transactions: accumulator.num_transactions,
+                num_non_vote_transactions: Some(accumulator.num_non_vote_transactions),
+                num_slots: accumulator.highest_slot.saturating_sub(start_slot),
+                sample_period_secs,
+            };
+
+            *accumulator = SampleAccumulator {
+                start_slot: Some(accumulator.highest_slot),
+                highest_slot: accumulator.highest_slot,
+                ..SampleAccumulator::default()
+            };
+            sample
+        };
+
+        let mut samples = self.samples.lock().unwrap();
+        samples.push_front(sample);
+        samples.truncate(MAX_PERFORMANCE_SAMPLES);
+    }
+
+    pub fn get_recent_samples(&self, limit: usize) -> Vec<RpcPerfSample> {
+        self.samples
+            .lock()
+            .unwrap()
+            .iter()
+            .take(limit)
+            .cloned()
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::structures::produced_block::TransactionInfo;
+    use solana_sdk::commitment_config::CommitmentConfig;
+
+    fn create_block(
+        slot: Slot,
+        commitment_config: CommitmentConfig,
+        votes: usize,
+    ) -> ProducedBlock {
+        let transactions = (0..3)
+            .map(|i| TransactionInfo {
+                signature: format!("signature-{slot}-{i}"),
+                signatures: vec![format!("signature-{slot}-{i}")],
+                is_vote: i < votes,
+                ..TransactionInfo::new_for_test()
+            })
+            .collect();
+
+        ProducedBlock {
+            transactions,
+            leader_id: None,
+            blockhash: String::new(),
+            block_height: slot,
+            slot,
+            parent_slot: slot - 1,
+            block_time: 0,
+            commitment_config,
+            previous_blockhash: String::new(),
+            rewards: None,
+        }
+    }
+
+    #[test]
+    fn sample_confirmed_blocks() {
+        let store = PerformanceSamplesStore::default();
+
+        // nothing to sample yet
+        store.take_sample(60);
+        assert!(store.get_recent_samples(10).is_empty());
+
+        store.add_block(&create_block(100, CommitmentConfig::confirmed(), 2));
+        store.add_block(&create_block(102, CommitmentConfig::confirmed(), 1));
+        // counted once as confirmed
+        store.add_block(&create_block(100, CommitmentConfig::finalized(), 2));
+        store.take_sample(60);
+
+        store.add_block(&create_block(105, CommitmentConfig::confirmed(), 3));
+        store.take_sample(60);
+
+        let samples = store.get_recent_samples(10);
+        assert_eq!(samples.len(), 2);
+
+        assert_eq!(samples[0].slot, 105);
+        assert_eq!(samples[0].num_slots, 3);
+        assert_eq!(samples[0].num_transactions, 3);
+        assert_eq!(samples[0].num_non_vote_transactions, Some(0));
+
+        assert_eq!(samples[1].slot, 102);
+        assert_eq!(samples[1].num_slots, 3);
+        assert_eq!(samples[1].num_transactions, 6);
+        assert_eq!(samples[1].num_non_vote_transactions, Some(3));
+        assert_eq!(samples[1].sample_period_secs, 60);
+
+        assert_eq!(store.get_recent_samples(1).len(), 1);
+    }
+}
@@ -0,0 +1,488 @@
+use std::collections::HashSet;
+use std::sync::Arc;
+
+use anyhow::Context;
+use dashmap::mapref::entry::Entry;
+use dashmap::DashMap;
+use serde_json::json;
+use solana_account_decoder::parse_token::spl_token_ids;
+use solana_account_decoder::UiAccountEncoding;
+use solana_rpc_client::nonblocking::rpc_client::RpcClient;
+use solana_rpc_client_api::config::{RpcAccountInfoConfig, RpcProgramAccountsConfig};
+use solana_rpc_client_api::filter::RpcFilterType;
+use solana_rpc_client_api::request::RpcRequest;
+use solana_rpc_client_api::response::{OptionalContext, RpcKeyedAccount};
+use solana_sdk::account::{Account, ReadableAccount};
+use solana_sdk::commitment_config::CommitmentConfig;
+use solana_sdk::pubkey::Pubkey;
+
+use crate::stores::account_store::AccountVersions;
+use crate::structures::account_data::AccountData;
+use crate::structures::token_account::TokenAccount;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum TokenAccountKey {
+    Owner(Pubkey),
+    Delegate(Pubkey),
+    Mint(Pubkey),
+}
+
+/// secondary index of the token accounts, over the states of all commitment levels
+#[derive(Clone, Default)]
+struct TokenAccountIndex {
+    by_owner: Arc<DashMap<Pubkey, HashSet<Pubkey>>>,
+    by_delegate: Arc<DashMap<Pubkey, HashSet<Pubkey>>>,
+    by_mint: Arc<DashMap<Pubkey, HashSet<Pubkey>>>,
+    // keys each token account is indexed under, to remove the ones which do not apply anymore
+    keys_by_account: Arc<DashMap<Pubkey, HashSet<TokenAccountKey>>>,
+}
+
+impl TokenAccountIndex {
+    /// replaces the keys of the account; no keys once it is closed or not a token account anymore
+    fn index(&self, pubkey: Pubkey, keys: HashSet<TokenAccountKey>) {
+        let previous_keys = if keys.is_empty() {
+            self.keys_by_account.remove(&pubkey).map(|(_, keys)| keys)
+        } else {
+            self.keys_by_account.insert(pubkey, keys.clone())
+        }
+        .unwrap_or_default();
+
+        for key in previous_keys.difference(&keys) {
+            let (index, key) = self.index_of(key);
+            if let Entry::Occupied(mut pubkeys) = index.entry(*key) {
+                pubkeys.get_mut().remove(&pubkey);
+                if pubkeys.get().is_empty() {
+                    pubkeys.remove();
+                }
+            }
+        }
+        for key in keys.difference(&previous_keys) {
+            let (index, key) = self.index_of(key);
+            index.entry(*key).or_default().insert(pubkey);
+        }
+    }
+
+    fn get(&self, key: &TokenAccountKey) -> Vec<Pubkey> {
+        let (index, key) = self.index_of(key);
+        index
+            .get(key)
+            .map(|pubkeys| pubkeys.iter().copied().collect())
+            .unwrap_or_default()
+    }
+
+    fn index_of<'a>(
+        &'a self,
+        key: &'a TokenAccountKey,
+    ) -> (&'a DashMap<Pubkey, HashSet<Pubkey>>, &'a Pubkey) {
+        match key {
+            TokenAccountKey::Owner(owner) => (&self.by_owner, owner),
+            TokenAccountKey::Delegate(delegate) => (&self.by_delegate, delegate),
+            TokenAccountKey::Mint(mint) => (&self.by_mint, mint),
+        }
+    }
+}
+
+fn token_account_keys(token_account: &TokenAccount) -> Vec<TokenAccountKey> {
+    let mut keys = vec![
+        TokenAccountKey::Owner(token_account.owner),
+        TokenAccountKey::Mint(token_account.mint),
+    ];
+    if let Some(delegate) = token_account.delegate {
+        keys.push(TokenAccountKey::Delegate(delegate));
+    }
+    keys
+}
+
+/// all the accounts owned by the indexed programs, kept up to date by the account stream
+#[derive(Clone, Default)]
+pub struct ProgramAccountStore {
+    // indexed programs, true once bootstrapped from rpc
+    programs: Arc<DashMap<Pubkey, bool>>,
+    accounts: Arc<DashMap<Pubkey, AccountVersions>>,
+    // accounts owned by the program at some commitment level
+    accounts_by_program: Arc<DashMap<Pubkey, HashSet<Pubkey>>>,
+    token_accounts: TokenAccountIndex,
+}
+
+impl ProgramAccountStore {
+    /// accounts of the program are indexed from the account stream; they are served after the bootstrap
+    pub fn add_program(&self, program_id: Pubkey) {
+        self.programs.entry(program_id).or_insert(false);
+    }
+
+    /// load the accounts of the program from rpc, updates received from the stream in the meantime are kept
+    pub async fn bootstrap_program(
+        &self,
+        rpc_client: &RpcClient,
+        program_id: Pubkey,
+    ) -> anyhow::Result<()> {
+        let config = RpcProgramAccountsConfig {
+            account_config: RpcAccountInfoConfig {
+                encoding: Some(UiAccountEncoding::Base64Zstd),
+                commitment: Some(CommitmentConfig::finalized()),
+                ..Default::default()
+            },
+            with_context: Some(true),
+            ..Default::default()
+        };
+        // send the request directly, the rpc client drops the context slot
+        let response = rpc_client
+            .send::<OptionalContext<Vec<RpcKeyedAccount>>>(
+                RpcRequest::GetProgramAccounts,
+                json!([program_id.to_string(), config]),
+            )
+            .await
+            .context("Failed to get program accounts")?;
+        let OptionalContext::Context(response) = response else {
+            anyhow::bail!("Program accounts without context");
+        };
+
+        for keyed_account in response.value {
+            let pubkey = keyed_account.pubkey.parse::<Pubkey>()?;
+            let account = keyed_account
+                .account
+                .decode::<Account>()
+                .context("Failed to decode program account")?;
+            self.update_account(
+                AccountData {
+                    pubkey,
+                    account: account.into(),
+                    updated_slot: response.context.slot,
+                    write_version: 0,
+                },
+                CommitmentConfig::finalized(),
+            );
+        }
+        self.programs.insert(program_id, true);
+        Ok(())
+    }
+
+    pub fn is_indexed(&self, program_id: &Pubkey) -> bool {
+        self.programs.contains_key(program_id)
+    }
+
+    /// update from the account stream, ignored unless the account is or was owned by an indexed program
+    pub fn update_account(&self, data: AccountData, commitment_config: CommitmentConfig) {
+        let owner = *data.account.owner();
+        let pubkey = data.pubkey;
+        if self.is_indexed(&owner) {
+            self.accounts_by_program
+                .entry(owner)
+                .or_default()
+                .insert(pubkey);
+        } else if !self.accounts.contains_key(&pubkey) {
+            return;
+        }
+
+        let is_closed = data.account.lamports() == 0;
+        let mut versions = self.accounts.entry(pubkey).or_default();
+        versions.update(data, commitment_config);
+        // forget the account once it is closed for good
+        let closed_for_good = is_closed
+            && commitment_config.is_finalized()
+            && versions
+                .get(CommitmentConfig::processed())
+                .map_or(true, |data| data.account.lamports() == 0);
+        let token_account_keys = if closed_for_good {
+            HashSet::new()
+        } else {
+            [
+                CommitmentConfig::processed(),
+                CommitmentConfig::confirmed(),
+                CommitmentConfig::finalized(),
+            ]
+            .into_iter()
+            .filter_map(|commitment_config| versions.get(commitment_config))
+            .filter(|data| data.account.lamports() > 0)
+            .filter_map(TokenAccount::parse)
+            .flat_map(|token_account| token_account_keys(&token_account))
+            .collect()
+        };
+        drop(versions);
+        self.token_accounts.index(pubkey, token_account_keys);
+        if closed_for_good {
+            self.accounts.remove(&pubkey);
+            if let Some(mut accounts) = self.accounts_by_program.get_mut(&owner) {
+                accounts.remove(&pubkey);
+            }
+        }
+    }
+
+    /// account owned by an indexed program
+    pub fn get_account(
+        &self,
+        pubkey: &Pubkey,
+        commitment_config: CommitmentConfig,
+    ) -> Option<AccountData> {
+        let versions = self.accounts.get(pubkey)?;
+        let data = versions.get(commitment_config)?;
+        let is_served = self
+            .programs
+            .get(data.account.owner())
+            .is_some_and(|bootstrapped| *bootstrapped);
+        (is_served && data.account.lamports() > 0).then(|| data.clone())
+    }
+
+    /// None if the program is not indexed or not bootstrapped yet
+    pub fn get_program_accounts(
+        &self,
+        program_id: &Pubkey,
+        commitment_config: CommitmentConfig,
+        filters: &[RpcFilterType],
+    ) -> Option<Vec<AccountData>> {
+        if !*self.programs.get(program_id)? {
+            return None;
+        }
+        let Some(pubkeys) = self.accounts_by_program.get(program_id) else {
+            return Some(vec![]);
+        };
+
+        let program_accounts = pubkeys
+            .iter()
+            .filter_map(|pubkey| {
+                let versions = self.accounts.get(pubkey)?;
+                let data = versions.get(commitment_config)?;
+                // the account may have been closed or assigned to another program since
+                (data.account.owner() == program_id
+                    && data.account.lamports() > 0
+                    && data.allows(filters))
+                .then(|| data.clone())
+            })
+            .collect();
+        Some(program_accounts)
+    }
+
+    /// None unless spl token and token-2022 are indexed and bootstrapped
+    pub fn get_token_accounts(
+        &self,
+        key: &TokenAccountKey,
+        commitment_config: CommitmentConfig,
+    ) -> Option<Vec<(AccountData, TokenAccount)>> {
+        for program_id in spl_token_ids() {
+            if !*self.programs.get(&program_id)? {
+                return None;
+            }
+        }
+
+        let token_accounts = self
+            .token_accounts
+            .get(key)
+            .into_iter()
+            .filter_map(|pubkey| {
+                let versions = self.accounts.get(&pubkey)?;
+                let data = versions.get(commitment_config)?;
+                if data.account.lamports() == 0 {
+                    return None;
+                }
+                // the key may only apply at another commitment level
+                let token_account = TokenAccount::parse(data)?;
+                let matches = match key {
+                    TokenAccountKey::Owner(owner) => token_account.owner == *owner,
+                    TokenAccountKey::Delegate(delegate) => {
+                        token_account.delegate == Some(*delegate)
+                    }
+                    TokenAccountKey::Mint(mint) => token_account.mint == *mint,
+                };
+                matches.then(|| (data.clone(), token_account))
+            })
+            .collect();
+        Some(token_accounts)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use solana_sdk::account::{AccountSharedData, WritableAccount};
+    use solana_sdk::program_option::COption;
+    use solana_sdk::program_pack::Pack;
+    use spl_token_2022::state::AccountState;
+
+    fn account_data(pubkey: Pubkey, owner: Pubkey, slot: u64, lamports: u64) -> AccountData {
+        AccountData {
+            pubkey,
+            account: AccountSharedData::from(Account {
+                lamports,
+                data: vec![0; 8],
+                owner,
+                executable: false,
+                rent_epoch: 0,
+            }),
+            updated_slot: slot,
+            write_version: 0,
+        }
+    }
+
+    #[test]
+    fn index_program_accounts() {
+        let store = ProgramAccountStore::default();
+        let program_id = Pubkey::new_unique();
+        store.add_program(program_id);
+
+        let (first, second) = (Pubkey::new_unique(), Pubkey::new_unique());
+        store.update_account(
+            account_data(first, program_id, 10, 1),
+            CommitmentConfig::confirmed(),
+        );
+        // not bootstrapped
+        assert!(store
+            .get_program_accounts(&program_id, CommitmentConfig::confirmed(), &[])
+            .is_none());
+        store.programs.insert(program_id, true);
+
+        store.update_account(
+            account_data(second, program_id, 11, 1),
+            CommitmentConfig::confirmed(),
+        );
+        // other programs are ignored
+        store.update_account(
+            account_data(Pubkey::new_unique(), Pubkey::new_unique(), 11, 1),
+            CommitmentConfig::confirmed(),
+        );
+        let program_accounts = |commitment_config, filters: &[RpcFilterType]| {
+            let mut pubkeys = store
+                .get_program_accounts(&program_id, commitment_config, filters)
+                .unwrap()
+                .into_iter()
+                .map(|data| data.pubkey)
+                .collect::<Vec<_>>();
+            pubkeys.sort();
+            pubkeys
+        };
+        let mut both = vec![first, second];
+        both.sort();
+        assert_eq!(program_accounts(CommitmentConfig::confirmed(), &[]), both);
+        assert!(program_accounts(CommitmentConfig::finalized(), &[]).is_empty());
+        assert!(
+            program_accounts(CommitmentConfig::confirmed(), &[RpcFilterType::DataSize(7)])
+                .is_empty()
+        );
+
+        // closed
+        store.update_account(
+            account_data(first, program_id, 12, 0),
+            CommitmentConfig::confirmed(),
+        );
+        assert_eq!(
+            program_accounts(CommitmentConfig::confirmed(), &[]),
+            vec![second]
+        );
+        assert!(store
+            .get_account(&first, CommitmentConfig::confirmed())
+            .is_none());
+
+        store.update_account(
+            account_data(first, program_id, 12, 0),
+            CommitmentConfig::finalized(),
+        );
+        assert!(!store.accounts.contains_key(&first));
+    }
+
+    #[test]
+    fn index_token_accounts_by_owner_delegate_and_mint() {
+        let store = ProgramAccountStore::default();
+        for program_id in spl_token_ids() {
+            store.add_program(program_id);
+        }
+
+        let (owner, delegate, mint) = (
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+        );
+        let token_account = |owner: Pubkey, slot: u64| {
+            let mut data = vec![0; spl_token_2022::state::Account::LEN];
+            spl_token_2022::state::Account::pack(
+                spl_token_2022::state::Account {
+                    mint,
+                    owner,
+                    delegate: COption::Some(delegate),
+                    amount: 42,
+                    state: AccountState::Initialized,
+                    ..Default::default()
+                },
+                &mut data,
+            )
+            .unwrap();
+            AccountData {
+                pubkey: Pubkey::default(),
+                account: AccountSharedData::from(Account {
+                    lamports: 1,
+                    data,
+                    owner: spl_token_2022::id(),
+                    executable: false,
+                    rent_epoch: 0,
+                }),
+                updated_slot: slot,
+                write_version: 0,
+            }
+        };
+        let pubkey = Pubkey::new_unique();
+        store.update_account(
+            AccountData {
+                pubkey,
+                ..token_account(owner, 10)
+            },
+            CommitmentConfig::confirmed(),
+        );
+        // not bootstrapped
+        assert!(store
+            .get_token_accounts(
+                &TokenAccountKey::Owner(owner),
+                CommitmentConfig::confirmed()
+            )
+            .is_none());
+        for program_id in spl_token_ids() {
+            store.programs.insert(program_id, true);
+        }
+
+        let token_accounts = |key| {
+            store
+                .get_token_accounts(&key, CommitmentConfig::confirmed())
+                .unwrap()
+                .into_iter()
+                .map(|(data, token_account)| (data.pubkey, token_account.amount))
+                .collect::<Vec<_>>()
+        };
+        assert_eq!(
+            token_accounts(TokenAccountKey::Owner(owner)),
+            vec![(pubkey, 42)]
+        );
+        assert_eq!(
+            token_accounts(TokenAccountKey::Delegate(delegate)),
+            vec![(pubkey, 42)]
+        );
+        assert_eq!(
+            token_accounts(TokenAccountKey::Mint(mint)),
+            vec![(pubkey, 42)]
+        );
+
+        // transferred to a new owner
+        let new_owner = Pubkey::new_unique();
+        store.update_account(
+            AccountData {
+                pubkey,
+                ..token_account(new_owner, 11)
+            },
+            CommitmentConfig::confirmed(),
+        );
+        assert!(token_accounts(TokenAccountKey::Owner(owner)).is_empty());
+        assert_eq!(
+            token_accounts(TokenAccountKey::Owner(new_owner)),
+            vec![(pubkey, 42)]
+        );
+        assert!(!store.token_accounts.by_owner.contains_key(&owner));
+
+        // closed for good
+        let mut closed = token_account(new_owner, 12);
+        closed.pubkey = pubkey;
+        closed.account.set_lamports(0);
+        store.update_account(closed.clone(), CommitmentConfig::confirmed());
+        assert!(token_accounts(TokenAccountKey::Mint(mint)).is_empty());
+        store.update_account(closed, CommitmentConfig::finalized());
+        assert!(store.token_accounts.by_owner.is_empty());
+        assert!(store.token_accounts.by_delegate.is_empty());
+        assert!(store.token_accounts.by_mint.is_empty());
+        assert!(store.token_accounts.keys_by_account.is_empty());
+    }
+}
@@ -1,16 +1,39 @@
-use crate::{structures::produced_block::TransactionInfo, types::SubscptionHanderSink};
+use crate::{
+    stores::tx_store::TxStore, structures::produced_block::TransactionInfo,
+    types::SubscptionHanderSink,
+};
 use dashmap::DashMap;
 use solana_sdk::{
     commitment_config::{CommitmentConfig, CommitmentLevel},
     slot_history::Slot,
+    transaction::TransactionError,
 };
+use solana_transaction_status::{TransactionConfirmationStatus, TransactionStatus};
 use std::{sync::Arc, time::Duration};
 use tokio::time::Instant;
 
+const SUPPORTED_COMMITMENT_CONFIGS: [CommitmentConfig; 3] = [
+    CommitmentConfig {
+        commitment: CommitmentLevel::Processed,
+    },
+    CommitmentConfig {
+        commitment: CommitmentLevel::Confirmed,
+    },
+    CommitmentConfig {
+        commitment: CommitmentLevel::Finalized,
+    },
+];
+
+#[derive(Clone)]
+pub struct SignatureSubscription {
+    pub sink: SubscptionHanderSink,
+    pub subscribed_at: Instant,
+    pub enable_received_notification: bool,
+}
+
 #[derive(Clone, Default)]
 pub struct SubscriptionStore {
-    pub signature_subscribers:
-        Arc<DashMap<(String, CommitmentConfig), (SubscptionHanderSink, Instant)>>,
+    pub signature_subscribers: Arc<DashMap<(String, CommitmentConfig), SignatureSubscription>>,
 }
 
 impl SubscriptionStore {
@@ -24,21 +47,50 @@ impl SubscriptionStore {
                     commitment: CommitmentLevel::Finalized,
                 }
             }
+            CommitmentLevel::Processed | CommitmentLevel::Recent => CommitmentConfig {
+                commitment: CommitmentLevel::Processed,
+            },
             _ => CommitmentConfig {
                 commitment: CommitmentLevel::Confirmed,
             },
         }
     }
 
-    pub fn signature_subscribe(
+    /// the subscription is answered right away if the transaction already landed at the commitment
+    pub async fn signature_subscribe(
         &self,
         signature: String,
         commitment_config: CommitmentConfig,
         sink: SubscptionHanderSink,
+        enable_received_notification: bool,
+        tx_store: &TxStore,
     ) {
         let commitment_config = Self::get_supported_commitment_config(commitment_config);
-        self.signature_subscribers
-            .insert((signature, commitment_config), (sink, Instant::now()));
+        self.signature_subscribers.insert(
+            (signature.clone(), commitment_config),
+            SignatureSubscription {
+                sink,
+                subscribed_at: Instant::now(),
+                enable_received_notification,
+            },
+        );
+
+        // checked after the insert so a concurrent block notification cannot be missed
+        let Some(status) = tx_store.get(&signature).and_then(|props| props.status) else {
+            return;
+        };
+        if !Self::has_landed(&status, commitment_config) {
+            return;
+        }
+        if let Some((_, subscription)) = self
+            .signature_subscribers
+            .remove(&(signature, commitment_config))
+        {
+            subscription
+                .sink
+                .send(status.slot, serde_json::json!({ "err": status.err }))
+                .await;
+        }
     }
 
     pub fn signature_un_subscribe(&self, signature: String, commitment_config: CommitmentConfig) {
@@ -53,22 +105,274 @@ impl SubscriptionStore {
         transaction_info: &TransactionInfo,
         commitment_config: CommitmentConfig,
     ) {
-        if let Some((_sig, (sink, _))) = self
+        // a block also notifies the subscriptions of lower commitment levels
+        let subscription_commitment_configs =
+            SUPPORTED_COMMITMENT_CONFIGS
+                .into_iter()
+                .filter(|subscription_commitment_config| {
+                    commitment_config.is_finalized()
+                        || subscription_commitment_config.is_processed()
+                        || commitment_config.is_confirmed()
+                            && subscription_commitment_config.is_confirmed()
+                });
+        for subscription_commitment_config in subscription_commitment_configs {
+            if let Some((_sig, subscription)) = self.signature_subscribers.remove(&(
+                transaction_info.signature.clone(),
+                subscription_commitment_config,
+            )) {
+                // none if transaction succeeded
+                subscription
+                    .sink
+                    .send(slot, serde_json::json!({ "err": transaction_info.err }))
+                    .await;
+            }
+        }
+    }
+
+    /// the transaction was forwarded to the leaders; the subscriptions stay open
+    /// a subscription is notified once, even when the transaction is sent again
+    pub async fn notify_received(&self, slot: Slot, signature: &str) {
+        for commitment_config in SUPPORTED_COMMITMENT_CONFIGS {
+            let sink = self
+                .signature_subscribers
+                .get_mut(&(signature.to_string(), commitment_config))
+                .and_then(|mut subscription| {
+                    std::mem::take(&mut subscription.enable_received_notification)
+                        .then(|| subscription.sink.clone())
+                });
+            if let Some(sink) = sink {
+                sink.send(slot, serde_json::json!("receivedSignature"))
+                    .await;
+            }
+        }
+    }
+
+    /// close the subscriptions of known transactions which did not land before their blockhash expired
+    pub async fn notify_expired(
+        &self,
+        slot: Slot,
+        finalized_block_height: u64,
+        tx_store: &TxStore,
+    ) {
+        let expired = self
             .signature_subscribers
-            .remove(&(transaction_info.signature.clone(), commitment_config))
-        {
-            // none if transaction succeeded
-            sink.send(slot, serde_json::json!({ "err": transaction_info.err }))
-                .await;
+            .iter()
+            .filter(|entry| {
+                let (signature, commitment_config) = entry.key();
+                tx_store.get(signature).is_some_and(|props| {
+                    props.last_valid_blockheight < finalized_block_height
+                        && !props
+                            .status
+                            .is_some_and(|status| Self::has_landed(&status, *commitment_config))
+                })
+            })
+            .map(|entry| entry.key().clone())
+            .collect::<Vec<_>>();
+
+        for key in expired {
+            if let Some((_, subscription)) = self.signature_subscribers.remove(&key) {
+                Self::send_expired(&subscription, slot).await;
+            }
         }
     }
 
+    /// drop the closed subscriptions; the ones older than the ttl are closed with an error, their transaction
+    /// may still land
     pub fn clean(&self, ttl_duration: Duration) {
-        self.signature_subscribers
-            .retain(|_k, (sink, instant)| !sink.is_closed() && instant.elapsed() < ttl_duration);
+        let mut timed_out = vec![];
+        self.signature_subscribers.retain(|_k, subscription| {
+            if subscription.sink.is_closed() {
+                return false;
+            }
+            if subscription.subscribed_at.elapsed() >= ttl_duration {
+                timed_out.push(subscription.clone());
+                return false;
+            }
+            true
+        });
+
+        for subscription in timed_out {
+            subscription.sink.close_with_error(format!(
+                "signature subscription timed out after {}s without a result, the transaction status is unknown",
+                ttl_duration.as_secs()
+            ));
+        }
     }
 
     pub fn number_of_subscribers(&self) -> usize {
         self.signature_subscribers.len()
     }
+
+    fn has_landed(status: &TransactionStatus, commitment_config: CommitmentConfig) -> bool {
+        match status.confirmation_status {
+            Some(TransactionConfirmationStatus::Finalized) => true,
+            Some(TransactionConfirmationStatus::Confirmed) => !commitment_config.is_finalized(),
+            Some(TransactionConfirmationStatus::Processed) => commitment_config.is_processed(),
+            None => false,
+        }
+    }
+
+    // same error as a transaction sent with an expired blockhash
+    async fn send_expired(subscription: &SignatureSubscription, slot: Slot) {
+        subscription
+            .sink
+            .send(
+                slot,
+                serde_json::json!({ "err": TransactionError::BlockhashNotFound }),
+            )
+            .await;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::stores::tx_store::TxProps;
+    use crate::traits::subscription_sink::SubscriptionSink;
+    use async_trait::async_trait;
+    use std::sync::Mutex;
+
+    #[derive(Default)]
+    struct TestSink {
+        messages: Mutex<Vec<(Slot, serde_json::Value)>>,
+        error: Mutex<Option<String>>,
+    }
+
+    #[async_trait]
+    impl SubscriptionSink for TestSink {
+        async fn send(&self, slot: Slot, message: serde_json::Value) {
+            self.messages.lock().unwrap().push((slot, message));
+        }
+
+        fn close_with_error(&self, error: String) {
+            *self.error.lock().unwrap() = Some(error);
+        }
+
+        fn is_closed(&self) -> bool {
+            false
+        }
+    }
+
+    fn tx_props(status: Option<TransactionConfirmationStatus>) -> TxProps {
+        TxProps {
+            status: status.map(|confirmation_status| TransactionStatus {
+                slot: 10,
+                confirmations: None,
+                status: Ok(()),
+                err: None,
+                confirmation_status: Some(confirmation_status),
+            }),
+            last_valid_blockheight: 100,
+            sent_by_lite_rpc: true,
+        }
+    }
+
+    #[test]
+    fn notify_received_landed_and_expired_signatures() {
+        futures::executor::block_on(async {
+            let store = SubscriptionStore::default();
+            let tx_store = TxStore {
+                store: Arc::new(DashMap::new()),
+            };
+            tx_store.insert(
+                "confirmed".to_string(),
+                tx_props(Some(TransactionConfirmationStatus::Confirmed)),
+            );
+            tx_store.insert("pending".to_string(), tx_props(None));
+
+            // already landed
+            let sink = Arc::new(TestSink::default());
+            store
+                .signature_subscribe(
+                    "confirmed".to_string(),
+                    CommitmentConfig::confirmed(),
+                    sink.clone(),
+                    false,
+                    &tx_store,
+                )
+                .await;
+            assert_eq!(
+                *sink.messages.lock().unwrap(),
+                vec![(10, serde_json::json!({ "err": null }))]
+            );
+
+            // not finalized yet
+            let finalized_sink = Arc::new(TestSink::default());
+            store
+                .signature_subscribe(
+                    "confirmed".to_string(),
+                    CommitmentConfig::finalized(),
+                    finalized_sink.clone(),
+                    false,
+                    &tx_store,
+                )
+                .await;
+            assert!(finalized_sink.messages.lock().unwrap().is_empty());
+
+            let pending_sink = Arc::new(TestSink::default());
+            store
+                .signature_subscribe(
+                    "pending".to_string(),
+                    CommitmentConfig::processed(),
+                    pending_sink.clone(),
+                    true,
+                    &tx_store,
+                )
+                .await;
+            store.notify_received(20, "pending").await;
+            store.notify_received(20, "confirmed").await;
+            // sent again
+            store.notify_received(25, "pending").await;
+            assert_eq!(
+                *pending_sink.messages.lock().unwrap(),
+                vec![(20, serde_json::json!("receivedSignature"))]
+            );
+            assert!(finalized_sink.messages.lock().unwrap().is_empty());
+            assert_eq!(store.number_of_subscribers(), 2);
+
+            // blockhash still valid
+            store.notify_expired(30, 100, &tx_store).await;
+            assert_eq!(store.number_of_subscribers(), 2);
+
+            store.notify_expired(40, 101, &tx_store).await;
+            assert_eq!(
+                pending_sink.messages.lock().unwrap().last(),
+                Some(&(40, serde_json::json!({ "err": "BlockhashNotFound" })))
+            );
+            assert_eq!(
+                *finalized_sink.messages.lock().unwrap(),
+                vec![(40, serde_json::json!({ "err": "BlockhashNotFound" }))]
+            );
+            assert_eq!(store.number_of_subscribers(), 0);
+        });
+    }
+
+    #[test]
+    fn close_subscriptions_after_ttl() {
+        futures::executor::block_on(async {
+            let store = SubscriptionStore::default();
+            let tx_store = TxStore {
+                store: Arc::new(DashMap::new()),
+            };
+            let sink = Arc::new(TestSink::default());
+            store
+                .signature_subscribe(
+                    "unknown".to_string(),
+                    CommitmentConfig::confirmed(),
+                    sink.clone(),
+                    false,
+                    &tx_store,
+                )
+                .await;
+
+            store.clean(Duration::from_secs(60));
+            assert_eq!(store.number_of_subscribers(), 1);
+
+            store.clean(Duration::ZERO);
+            assert_eq!(store.number_of_subscribers(), 0);
+            // no result, the transaction is not known to have expired
+            assert!(sink.messages.lock().unwrap().is_empty());
+            assert!(sink.error.lock().unwrap().is_some());
+        });
+    }
 }
@@ -1,7 +1,11 @@
 use dashmap::DashMap;
-use solana_transaction_status::TransactionStatus;
+use solana_sdk::slot_history::Slot;
+use solana_transaction_status::{TransactionConfirmationStatus, TransactionStatus};
 use std::sync::Arc;
 
+// transactions sent by lite-rpc which expired are reported for roughly a day
+pub const EXPIRED_TRANSACTIONS_RETENTION_BLOCKS: u64 = 216_000;
+
 /// Transaction Properties
 
 #[derive(Debug, Clone)]
@@ -11,6 +15,15 @@ pub struct TxProps {
     pub sent_by_lite_rpc: bool,
 }
 
+impl TxProps {
+    /// sent by lite-rpc and its blockhash expired before it landed
+    pub fn is_expired(&self, current_finalized_blockheight: u64) -> bool {
+        self.sent_by_lite_rpc
+            && self.status.is_none()
+            && self.last_valid_blockheight < current_finalized_blockheight
+    }
+}
+
 #[derive(Clone, Debug)]
 pub struct TxStore {
     pub store: Arc<DashMap<String, TxProps>>,
@@ -61,14 +74,42 @@ impl TxStore {
 
     pub fn clean(&self, current_finalized_blockheight: u64) {
         let length_before = self.store.len();
-        self.store
-            .retain(|_k, v| v.last_valid_blockheight >= current_finalized_blockheight);
+        self.store.retain(|_k, v| {
+            if v.is_expired(current_finalized_blockheight) {
+                v.last_valid_blockheight + EXPIRED_TRANSACTIONS_RETENTION_BLOCKS
+                    >= current_finalized_blockheight
+            } else {
+                v.last_valid_blockheight >= current_finalized_blockheight
+            }
+        });
         log::info!(
             "Cleaned {} transactions",
             length_before.saturating_sub(self.store.len())
         );
     }
 
+    /// forget the processed status of a transaction seen in a block of an abandoned fork
+    /// returns true if the status was rolled back
+    pub fn rollback_status(&self, signature: &String, slot: Slot) -> bool {
+        let Some(mut props) = self.store.get_mut(signature) else {
+            return false;
+        };
+        let seen_only_in_slot = props.status.as_ref().is_some_and(|status| {
+            status.slot == slot
+                && status.confirmation_status == Some(TransactionConfirmationStatus::Processed)
+        });
+        if !seen_only_in_slot {
+            return false;
+        }
+        if props.sent_by_lite_rpc {
+            props.status = None;
+        } else {
+            drop(props);
+            self.store.remove(signature);
+        }
+        true
+    }
+
     pub fn is_transaction_confirmed(&self, signature: &String) -> bool {
         match self.store.get(signature) {
             Some(props) => props.status.is_some(),
@@ -76,3 +117,67 @@ impl TxStore {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn keep_expired_transactions_sent_by_lite_rpc() {
+        let store = TxStore {
+            store: Arc::new(DashMap::new()),
+        };
+        let props = |sent_by_lite_rpc| TxProps {
+            status: None,
+            last_valid_blockheight: 100,
+            sent_by_lite_rpc,
+        };
+        store.insert("sent".to_string(), props(true));
+        store.insert("seen".to_string(), props(false));
+
+        store.clean(101);
+        assert!(store.get(&"sent".to_string()).unwrap().is_expired(101));
+        assert!(!store.contains_key(&"seen".to_string()));
+
+        store.clean(101 + EXPIRED_TRANSACTIONS_RETENTION_BLOCKS);
+        assert!(store.is_empty());
+    }
+
+    #[test]
+    fn rollback_transactions_of_abandoned_forks() {
+        let store = TxStore {
+            store: Arc::new(DashMap::new()),
+        };
+        let props = |sent_by_lite_rpc, confirmation_status| TxProps {
+            status: Some(TransactionStatus {
+                slot: 10,
+                confirmations: None,
+                status: Ok(()),
+                err: None,
+                confirmation_status: Some(confirmation_status),
+            }),
+            last_valid_blockheight: 100,
+            sent_by_lite_rpc,
+        };
+        store.insert(
+            "sent".to_string(),
+            props(true, TransactionConfirmationStatus::Processed),
+        );
+        store.insert(
+            "seen".to_string(),
+            props(false, TransactionConfirmationStatus::Processed),
+        );
+        store.insert(
+            "confirmed".to_string(),
+            props(false, TransactionConfirmationStatus::Confirmed),
+        );
+
+        // seen again in another block
+        assert!(!store.rollback_status(&"sent".to_string(), 9));
+        assert!(store.rollback_status(&"sent".to_string(), 10));
+        assert!(store.get(&"sent".to_string()).unwrap().status.is_none());
+        assert!(store.rollback_status(&"seen".to_string(), 10));
+        assert!(!store.contains_key(&"seen".to_string()));
+        assert!(!store.rollback_status(&"confirmed".to_string(), 10));
+    }
+}
@@ -0,0 +1,148 @@
+use std::sync::Arc;
+
+use solana_rpc_client_api::request::DELINQUENT_VALIDATOR_SLOT_DISTANCE;
+use solana_rpc_client_api::response::{RpcVoteAccountInfo, RpcVoteAccountStatus};
+use solana_sdk::slot_history::Slot;
+use tokio::sync::RwLock;
+
+use crate::structures::leaderschedule::GetVoteAccountsConfig;
+
+/// apply the getVoteAccounts parameters to the unfiltered vote accounts (unstaked delinquents included)
+pub fn filter_vote_accounts(
+    vote_accounts: RpcVoteAccountStatus,
+    current_slot: Slot,
+    config: &GetVoteAccountsConfig,
+) -> RpcVoteAccountStatus {
+    let delinquent_slot_distance = config
+        .delinquent_slot_distance
+        .unwrap_or(DELINQUENT_VALIDATOR_SLOT_DISTANCE);
+    let keep_unstaked_delinquents = config.keep_unstaked_delinquents.unwrap_or_default();
+    let vote_pubkey = config.vote_pubkey.map(|pubkey| pubkey.to_string());
+
+    // same partition as solana rpc
+    let (current, delinquent): (Vec<RpcVoteAccountInfo>, Vec<RpcVoteAccountInfo>) = vote_accounts
+        .current
+        .into_iter()
+        .chain(vote_accounts.delinquent)
+        .filter(|info| {
+            vote_pubkey
+                .as_ref()
+                .map_or(true, |vote_pubkey| *vote_pubkey == info.vote_pubkey)
+        })
+        .partition(|info| {
+            if current_slot >= delinquent_slot_distance {
+                info.last_vote > current_slot - delinquent_slot_distance
+            } else {
+                info.last_vote > 0
+            }
+        });
+    let delinquent = delinquent
+        .into_iter()
+        .filter(|info| keep_unstaked_delinquents || info.activated_stake > 0)
+        .collect();
+
+    RpcVoteAccountStatus {
+        current,
+        delinquent,
+    }
+}
+
+/// latest vote accounts polled from the rpc, used when stakes are not computed from geyser
+#[derive(Debug, Clone, Default)]
+pub struct VoteAccountStore {
+    vote_accounts: Arc<RwLock<Option<RpcVoteAccountStatus>>>,
+}
+
+impl VoteAccountStore {
+    pub async fn update(&self, vote_accounts: RpcVoteAccountStatus) {
+        *self.vote_accounts.write().await = Some(vote_accounts);
+    }
+
+    /// None until the first poll
+    pub async fn get_vote_accounts(
+        &self,
+        current_slot: Slot,
+        config: &GetVoteAccountsConfig,
+    ) -> Option<RpcVoteAccountStatus> {
+        let vote_accounts = self.vote_accounts.read().await.clone()?;
+        Some(filter_vote_accounts(vote_accounts, current_slot, config))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use solana_sdk::pubkey::Pubkey;
+
+    fn vote_account(activated_stake: u64, last_vote: Slot) -> RpcVoteAccountInfo {
+        RpcVoteAccountInfo {
+            vote_pubkey: Pubkey::new_unique().to_string(),
+            node_pubkey: Pubkey::new_unique().to_string(),
+            activated_stake,
+            commission: 0,
+            epoch_vote_account: true,
+            epoch_credits: vec![],
+            last_vote,
+            root_slot: 0,
+        }
+    }
+
+    #[test]
+    fn filter_polled_vote_accounts() {
+        let current_slot = 1000;
+        let active = vote_account(10, 999);
+        let late = vote_account(10, 900);
+        let unstaked_delinquent = vote_account(0, 500);
+        let polled = RpcVoteAccountStatus {
+            current: vec![active.clone(), late.clone()],
+            delinquent: vec![unstaked_delinquent.clone()],
+        };
+
+        let vote_pubkeys = |accounts: &Vec<RpcVoteAccountInfo>| {
+            accounts
+                .iter()
+                .map(|info| info.vote_pubkey.clone())
+                .collect::<Vec<_>>()
+        };
+
+        let vote_accounts = filter_vote_accounts(
+            polled.clone(),
+            current_slot,
+            &GetVoteAccountsConfig::default(),
+        );
+        assert_eq!(
+            vote_pubkeys(&vote_accounts.current),
+            vec![active.vote_pubkey.clone(), late.vote_pubkey.clone()]
+        );
+        assert!(vote_accounts.delinquent.is_empty());
+
+        let vote_accounts = filter_vote_accounts(
+            polled.clone(),
+            current_slot,
+            &GetVoteAccountsConfig {
+                keep_unstaked_delinquents: Some(true),
+                delinquent_slot_distance: Some(50),
+                ..Default::default()
+            },
+        );
+        assert_eq!(
+            vote_pubkeys(&vote_accounts.current),
+            vec![active.vote_pubkey.clone()]
+        );
+        assert_eq!(
+            vote_pubkeys(&vote_accounts.delinquent),
+            vec![late.vote_pubkey.clone(), unstaked_delinquent.vote_pubkey]
+        );
+
+        let vote_accounts = filter_vote_accounts(
+            polled,
+            current_slot,
+            &GetVoteAccountsConfig {
+                vote_pubkey: Some(late.vote_pubkey.parse().unwrap()),
+                ..Default::default()
+            },
+        );
+        assert_eq!(vote_pubkeys(&vote_accounts.current), vec![late.vote_pubkey]);
+        assert!(vote_accounts.delinquent.is_empty());
+    }
+}
@@ -0,0 +1,90 @@
+use solana_rpc_client_api::filter::{RpcFilterError, RpcFilterType};
+use solana_sdk::account::AccountSharedData;
+use solana_sdk::commitment_config::CommitmentConfig;
+use solana_sdk::pubkey::Pubkey;
+use solana_sdk::slot_history::Slot;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AccountData {
+    pub pubkey: Pubkey,
+    pub account: AccountSharedData,
+    pub updated_slot: Slot,
+    // orders the updates of an account within a slot
+    pub write_version: u64,
+}
+
+impl AccountData {
+    /// true if the account passes all the filters (memcmp, dataSize, tokenAccountState)
+    pub fn allows(&self, filters: &[RpcFilterType]) -> bool {
+        filters.iter().all(|filter| filter.allows(&self.account))
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct AccountNotificationMessage {
+    pub data: AccountData,
+    pub commitment: CommitmentConfig,
+}
+
+/// verify the filters and decode the memcmp bytes once, so that they can be applied to many accounts
+pub fn prepare_filters(filters: Vec<RpcFilterType>) -> Result<Vec<RpcFilterType>, RpcFilterError> {
+    filters
+        .into_iter()
+        .map(|mut filter| {
+            filter.verify()?;
+            if let RpcFilterType::Memcmp(memcmp) = &mut filter {
+                memcmp.convert_to_raw_bytes()?;
+            }
+            Ok(filter)
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use solana_rpc_client_api::filter::{Memcmp, MemcmpEncodedBytes};
+    use solana_sdk::account::Account;
+
+    #[test]
+    fn filter_accounts() {
+        let owner = Pubkey::new_unique();
+        let account_data = AccountData {
+            pubkey: Pubkey::new_unique(),
+            account: AccountSharedData::from(Account {
+                lamports: 1_000_000,
+                data: owner.to_bytes().to_vec(),
+                owner: Pubkey::new_unique(),
+                executable: false,
+                rent_epoch: 0,
+            }),
+            updated_slot: 42,
+            write_version: 1,
+        };
+
+        let filters = prepare_filters(vec![
+            RpcFilterType::DataSize(32),
+            RpcFilterType::Memcmp(Memcmp::new(
+                0,
+                MemcmpEncodedBytes::Base58(owner.to_string()),
+            )),
+        ])
+        .unwrap();
+        assert!(account_data.allows(&filters));
+        assert!(account_data.allows(&[]));
+
+        let filters = prepare_filters(vec![RpcFilterType::Memcmp(Memcmp::new(
+            1,
+            MemcmpEncodedBytes::Base58(owner.to_string()),
+        ))])
+        .unwrap();
+        assert!(!account_data.allows(&filters));
+        assert!(!account_data.allows(&[RpcFilterType::DataSize(165)]));
+
+        assert!(prepare_filters(vec![RpcFilterType::Memcmp(Memcmp::new(
+            0,
+            MemcmpEncodedBytes::Base58("not base58 0OIl".to_string()),
+        ))])
+        .is_err());
+    }
+}
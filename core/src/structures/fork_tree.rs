@@ -0,0 +1,206 @@
+use std::collections::{BTreeMap, HashMap, HashSet};
+
+use solana_sdk::{commitment_config::CommitmentConfig, slot_history::Slot};
+
+use crate::structures::produced_block::ProducedBlock;
+
+#[derive(Debug, Clone)]
+pub struct ForkTreeNode {
+    pub slot: Slot,
+    pub parent_slot: Slot,
+    pub blockhash: String,
+    pub commitment_config: CommitmentConfig,
+    pub signatures: Vec<String>,
+}
+
+impl ForkTreeNode {
+    pub fn from_block(block: &ProducedBlock) -> Self {
+        Self {
+            slot: block.slot,
+            parent_slot: block.parent_slot,
+            blockhash: block.blockhash.clone(),
+            commitment_config: block.commitment_config,
+            signatures: block
+                .transactions
+                .iter()
+                .map(|tx| tx.signature.clone())
+                .collect(),
+        }
+    }
+}
+
+/// blocks of abandoned forks pruned when a block on another fork got confirmed or finalized
+#[derive(Debug, Clone)]
+pub struct ReorgNotification {
+    pub slot: Slot,
+    pub commitment_config: CommitmentConfig,
+    pub orphaned_blocks: Vec<ForkTreeNode>,
+    // length of the longest abandoned branch
+    pub depth: usize,
+}
+
+/// blocks above the latest finalized slot, linked to their parent by the parent slot
+#[derive(Debug)]
+pub struct ForkTree {
+    root_slot: Slot,
+    nodes: BTreeMap<Slot, ForkTreeNode>,
+}
+
+impl ForkTree {
+    pub fn new(finalized_slot: Slot) -> Self {
+        Self {
+            root_slot: finalized_slot,
+            nodes: BTreeMap::new(),
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self.nodes.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.nodes.is_empty()
+    }
+
+    pub fn contains(&self, slot: Slot) -> bool {
+        self.nodes.contains_key(&slot)
+    }
+
+    /// a confirmed or finalized block prunes every branch it does not belong to
+    pub fn add_block(&mut self, node: ForkTreeNode) -> Option<ReorgNotification> {
+        if node.slot <= self.root_slot {
+            return None;
+        }
+        let slot = node.slot;
+        let commitment_config = node.commitment_config;
+        match self.nodes.get_mut(&slot) {
+            // processed blocks carry the transactions, the commitment only moves up
+            Some(prev) if prev.blockhash == node.blockhash => {
+                if !prev.commitment_config.is_finalized() && !commitment_config.is_processed() {
+                    prev.commitment_config = commitment_config;
+                }
+            }
+            _ => {
+                self.nodes.insert(slot, node);
+            }
+        }
+        if commitment_config.is_processed() {
+            return None;
+        }
+
+        let orphaned_blocks = self.prune(slot);
+        if commitment_config.is_finalized() {
+            self.root_slot = slot;
+            self.nodes = self.nodes.split_off(&slot);
+        }
+
+        let depths = orphaned_blocks.iter().fold(
+            HashMap::<Slot, usize>::new(),
+            |mut depths, orphaned_block| {
+                let depth = depths.get(&orphaned_block.parent_slot).unwrap_or(&0) + 1;
+                depths.insert(orphaned_block.slot, depth);
+                depths
+            },
+        );
+        let depth = depths.into_values().max()?;
+        Some(ReorgNotification {
+            slot,
+            commitment_config,
+            orphaned_blocks,
+            depth,
+        })
+    }
+
+    // removes the blocks which are neither ancestors nor descendants of the block at the slot, ordered by slot
+    fn prune(&mut self, slot: Slot) -> Vec<ForkTreeNode> {
+        // ancestors known to the tree, the chain skipped every slot between a block and its parent
+        let mut ancestors = HashSet::from([slot]);
+        let mut lowest_known_parent = slot;
+        while let Some(node) = self.nodes.get(&lowest_known_parent) {
+            ancestors.insert(node.slot);
+            lowest_known_parent = node.parent_slot;
+        }
+
+        let mut orphaned = HashSet::new();
+        for node in self.nodes.values() {
+            let is_orphaned = if node.slot < slot {
+                node.slot > lowest_known_parent && !ancestors.contains(&node.slot)
+            } else if node.slot > slot {
+                node.parent_slot < slot || orphaned.contains(&node.parent_slot)
+            } else {
+                false
+            };
+            if is_orphaned {
+                orphaned.insert(node.slot);
+            }
+        }
+
+        let (orphaned, kept): (BTreeMap<_, _>, BTreeMap<_, _>) = std::mem::take(&mut self.nodes)
+            .into_iter()
+            .partition(|(node_slot, _)| orphaned.contains(node_slot));
+        self.nodes = kept;
+        orphaned.into_values().collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn node(slot: Slot, parent_slot: Slot, commitment_config: CommitmentConfig) -> ForkTreeNode {
+        ForkTreeNode {
+            slot,
+            parent_slot,
+            blockhash: format!("blockhash-{slot}"),
+            commitment_config,
+            signatures: vec![format!("signature-{slot}")],
+        }
+    }
+
+    fn orphaned_slots(reorg: &ReorgNotification) -> Vec<Slot> {
+        reorg
+            .orphaned_blocks
+            .iter()
+            .map(|block| block.slot)
+            .collect()
+    }
+
+    #[test]
+    fn prune_abandoned_forks() {
+        let mut tree = ForkTree::new(10);
+        let processed = CommitmentConfig::processed();
+
+        // 10 <- 11 <- 12 <- 13 and 10 <- 14 <- 15
+        for (slot, parent_slot) in [(11, 10), (12, 11), (13, 12), (14, 10), (15, 14)] {
+            assert!(tree.add_block(node(slot, parent_slot, processed)).is_none());
+        }
+        // slots older than the finalized root are ignored
+        assert!(tree.add_block(node(9, 8, processed)).is_none());
+        assert_eq!(tree.len(), 5);
+
+        let reorg = tree
+            .add_block(node(14, 10, CommitmentConfig::confirmed()))
+            .unwrap();
+        assert_eq!(orphaned_slots(&reorg), vec![11, 12, 13]);
+        assert_eq!(reorg.depth, 3);
+        assert_eq!(reorg.orphaned_blocks[0].signatures, vec!["signature-11"]);
+        assert!(tree.contains(14) && tree.contains(15));
+
+        // no other fork left
+        assert!(tree
+            .add_block(node(15, 14, CommitmentConfig::confirmed()))
+            .is_none());
+
+        // 16 forks off below the confirmed 14
+        assert!(tree.add_block(node(16, 10, processed)).is_none());
+        assert!(tree.add_block(node(17, 15, processed)).is_none());
+        let reorg = tree
+            .add_block(node(15, 14, CommitmentConfig::finalized()))
+            .unwrap();
+        assert_eq!(orphaned_slots(&reorg), vec![16]);
+        assert_eq!(reorg.depth, 1);
+        // the finalized block becomes the root
+        assert!(!tree.contains(14));
+        assert!(tree.contains(15) && tree.contains(17));
+    }
+}
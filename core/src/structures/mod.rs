@@ -1,6 +1,8 @@
 // this mod will contain all the core structures that are defined for lite-rpc
 
+pub mod account_data;
 pub mod epoch;
+pub mod fork_tree;
 pub mod identity_stakes;
 pub mod leader_data;
 pub mod leaderschedule;
@@ -9,4 +11,6 @@ pub mod produced_block;
 pub mod proxy_request_format;
 pub mod rotating_queue;
 pub mod slot_notification;
+pub mod token_account;
+pub mod transaction_filter;
 pub mod transaction_sent_info;
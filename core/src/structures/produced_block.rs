@@ -1,10 +1,31 @@
+use std::str::FromStr;
+
+use anyhow::Context;
+use solana_rpc_client_api::response::RpcVote;
 use solana_sdk::commitment_config::CommitmentConfig;
+use solana_sdk::message::v0::LoadedAddresses;
+use solana_sdk::message::VersionedMessage;
+use solana_sdk::program_utils::limited_deserialize;
+use solana_sdk::pubkey::Pubkey;
+use solana_sdk::signature::Signature;
+use solana_sdk::transaction::VersionedTransaction;
+use solana_sdk::transaction_context::TransactionReturnData;
+use solana_sdk::vote::instruction::VoteInstruction;
 use solana_sdk::{slot_history::Slot, transaction::TransactionError};
-use solana_transaction_status::Reward;
+use solana_transaction_status::{
+    BlockEncodingOptions, ConfirmedBlock, ConfirmedTransactionWithStatusMeta,
+    EncodedConfirmedTransactionWithStatusMeta, EncodedTransactionWithStatusMeta, InnerInstructions,
+    Reward, TransactionStatusMeta, TransactionWithStatusMeta, UiConfirmedBlock,
+    UiTransactionEncoding, VersionedConfirmedBlock, VersionedTransactionWithStatusMeta,
+};
+
+use crate::encoding::BASE64;
 
 #[derive(Debug, Clone)]
 pub struct TransactionInfo {
     pub signature: String,
+    // every signature of the transaction, the first one is `signature`
+    pub signatures: Vec<String>,
     pub is_vote: bool,
     pub err: Option<TransactionError>,
     pub cu_requested: Option<u32>,
@@ -12,6 +33,181 @@ pub struct TransactionInfo {
     pub cu_consumed: Option<u64>,
     pub recent_blockhash: String,
     pub message: String,
+    // accounts loaded from address lookup tables
+    pub loaded_addresses: LoadedAddresses,
+    pub fee: u64,
+    pub pre_balances: Vec<u64>,
+    pub post_balances: Vec<u64>,
+    pub inner_instructions: Option<Vec<InnerInstructions>>,
+    pub log_messages: Option<Vec<String>>,
+    pub return_data: Option<TransactionReturnData>,
+    // accounts write-locked by the transaction, including the ones loaded from address lookup tables
+    pub writable_accounts: Vec<Pubkey>,
+}
+
+#[cfg(any(test, feature = "test-utils"))]
+impl TransactionInfo {
+    /// placeholder transaction for tests, the fields a test depends on are set with struct update syntax
+    pub fn new_for_test() -> Self {
+        let signature = Signature::new_unique().to_string();
+        Self {
+            signatures: vec![signature.clone()],
+            signature,
+            is_vote: false,
+            err: None,
+            cu_requested: None,
+            prioritization_fees: None,
+            cu_consumed: None,
+            recent_blockhash: solana_sdk::hash::Hash::default().to_string(),
+            message: String::new(),
+            loaded_addresses: LoadedAddresses::default(),
+            fee: 5000,
+            pre_balances: vec![],
+            post_balances: vec![],
+            inner_instructions: None,
+            log_messages: None,
+            return_data: None,
+            writable_accounts: vec![],
+        }
+    }
+}
+
+impl TransactionInfo {
+    /// static account keys the message may write to followed by the writable keys loaded from address lookup tables
+    pub fn get_writable_accounts(
+        message: &VersionedMessage,
+        loaded_addresses: &LoadedAddresses,
+    ) -> Vec<Pubkey> {
+        message
+            .static_account_keys()
+            .iter()
+            .enumerate()
+            .filter(|(index, _)| message.is_maybe_writable(*index))
+            .map(|(_, key)| *key)
+            .chain(loaded_addresses.writable.iter().copied())
+            .collect()
+    }
+
+    /// rebuild the solana transaction with its status meta from what we keep in the block
+    pub fn to_versioned_transaction_with_status_meta(
+        &self,
+    ) -> anyhow::Result<VersionedTransactionWithStatusMeta> {
+        let signatures = self
+            .signatures
+            .iter()
+            .map(|signature| {
+                Signature::from_str(signature)
+                    .with_context(|| format!("invalid signature {}", signature))
+            })
+            .collect::<anyhow::Result<Vec<_>>>()?;
+        let message: VersionedMessage = BASE64
+            .deserialize(&self.message)
+            .with_context(|| format!("cannot decode message of transaction {}", self.signature))?;
+
+        let meta = TransactionStatusMeta {
+            status: self.err.clone().map_or(Ok(()), Err),
+            fee: self.fee,
+            pre_balances: self.pre_balances.clone(),
+            post_balances: self.post_balances.clone(),
+            inner_instructions: self.inner_instructions.clone(),
+            log_messages: self.log_messages.clone(),
+            loaded_addresses: self.loaded_addresses.clone(),
+            return_data: self.return_data.clone(),
+            compute_units_consumed: self.cu_consumed,
+            ..TransactionStatusMeta::default()
+        };
+
+        Ok(VersionedTransactionWithStatusMeta {
+            transaction: VersionedTransaction {
+                signatures,
+                message,
+            },
+            meta,
+        })
+    }
+
+    /// encode the transaction the same way blockSubscribe does for the given transaction details;
+    /// None when the details do not include the transaction
+    pub fn encode_with_options(
+        &self,
+        encoding: UiTransactionEncoding,
+        options: BlockEncodingOptions,
+    ) -> anyhow::Result<Option<EncodedTransactionWithStatusMeta>> {
+        // the accounts encoding is only exposed for blocks
+        let confirmed_block = ConfirmedBlock::from(VersionedConfirmedBlock {
+            previous_blockhash: String::default(),
+            blockhash: String::default(),
+            parent_slot: 0,
+            transactions: vec![self.to_versioned_transaction_with_status_meta()?],
+            rewards: vec![],
+            block_time: None,
+            block_height: None,
+        });
+        let ui_block = confirmed_block.encode_with_options(encoding, options)?;
+
+        Ok(ui_block
+            .transactions
+            .and_then(|transactions| transactions.into_iter().next()))
+    }
+
+    /// static account keys of the message followed by the keys loaded from address lookup tables
+    pub fn account_keys(&self) -> anyhow::Result<Vec<Pubkey>> {
+        let message: VersionedMessage = BASE64
+            .deserialize(&self.message)
+            .with_context(|| format!("cannot decode message of transaction {}", self.signature))?;
+
+        Ok(message
+            .static_account_keys()
+            .iter()
+            .chain(self.loaded_addresses.writable.iter())
+            .chain(self.loaded_addresses.readonly.iter())
+            .copied()
+            .collect())
+    }
+
+    /// votes of the simple vote instructions of the transaction
+    pub fn get_votes(&self) -> anyhow::Result<Vec<RpcVote>> {
+        let message: VersionedMessage = BASE64
+            .deserialize(&self.message)
+            .with_context(|| format!("cannot decode message of transaction {}", self.signature))?;
+        let account_keys = message.static_account_keys();
+
+        Ok(message
+            .instructions()
+            .iter()
+            .filter(|instruction| {
+                instruction
+                    .program_id(account_keys)
+                    .eq(&solana_sdk::vote::program::id())
+            })
+            .filter_map(|instruction| {
+                let vote_instruction =
+                    limited_deserialize::<VoteInstruction>(&instruction.data).ok()?;
+                let (slots, hash) = match &vote_instruction {
+                    VoteInstruction::Vote(vote) | VoteInstruction::VoteSwitch(vote, _) => {
+                        (vote.slots.clone(), vote.hash)
+                    }
+                    VoteInstruction::UpdateVoteState(vote_state_update)
+                    | VoteInstruction::UpdateVoteStateSwitch(vote_state_update, _)
+                    | VoteInstruction::CompactUpdateVoteState(vote_state_update)
+                    | VoteInstruction::CompactUpdateVoteStateSwitch(vote_state_update, _) => {
+                        (vote_state_update.slots(), vote_state_update.hash)
+                    }
+                    _ => return None,
+                };
+                // first account of a vote instruction is the vote account
+                let vote_pubkey = account_keys.get(*instruction.accounts.first()? as usize)?;
+
+                Some(RpcVote {
+                    vote_pubkey: vote_pubkey.to_string(),
+                    slots,
+                    hash: hash.to_string(),
+                    timestamp: vote_instruction.timestamp(),
+                    signature: self.signature.clone(),
+                })
+            })
+            .collect())
+    }
 }
 
 // TODO try to remove Clone
@@ -45,4 +241,400 @@ impl ProducedBlock {
             ..self.clone()
         }
     }
+
+    /// encode the block the same way solana rpc does for getBlock
+    pub fn encode_with_options(
+        &self,
+        encoding: UiTransactionEncoding,
+        options: BlockEncodingOptions,
+    ) -> anyhow::Result<UiConfirmedBlock> {
+        let transactions = self
+            .transactions
+            .iter()
+            .map(TransactionInfo::to_versioned_transaction_with_status_meta)
+            .collect::<anyhow::Result<Vec<_>>>()?;
+
+        let confirmed_block = ConfirmedBlock::from(VersionedConfirmedBlock {
+            previous_blockhash: self.previous_blockhash.clone(),
+            blockhash: self.blockhash.clone(),
+            parent_slot: self.parent_slot,
+            transactions,
+            rewards: self.rewards.clone().unwrap_or_default(),
+            block_time: Some(self.block_time as i64),
+            block_height: Some(self.block_height),
+        });
+
+        Ok(confirmed_block.encode_with_options(encoding, options)?)
+    }
+
+    /// copy of the block keeping only the transactions which reference the account (static or loaded)
+    pub fn with_transactions_mentioning(&self, account: &Pubkey) -> Self {
+        let transactions = self
+            .transactions
+            .iter()
+            .filter(|tx| {
+                tx.account_keys()
+                    .map(|account_keys| account_keys.contains(account))
+                    .unwrap_or_default()
+            })
+            .cloned()
+            .collect();
+
+        ProducedBlock {
+            transactions,
+            leader_id: self.leader_id.clone(),
+            blockhash: self.blockhash.clone(),
+            block_height: self.block_height,
+            slot: self.slot,
+            parent_slot: self.parent_slot,
+            block_time: self.block_time,
+            commitment_config: self.commitment_config,
+            previous_blockhash: self.previous_blockhash.clone(),
+            rewards: self.rewards.clone(),
+        }
+    }
+
+    /// transaction of the block with the given signature
+    pub fn get_transaction(&self, signature: &str) -> Option<ConfirmedTransactionInfo> {
+        self.transactions
+            .iter()
+            .find(|tx| tx.signature == signature)
+            .map(|tx| ConfirmedTransactionInfo {
+                slot: self.slot,
+                block_time: self.block_time,
+                transaction: tx.clone(),
+            })
+    }
+}
+
+/// transaction together with the slot and time of the block it was included in
+#[derive(Debug, Clone)]
+pub struct ConfirmedTransactionInfo {
+    pub slot: Slot,
+    pub block_time: u64,
+    pub transaction: TransactionInfo,
+}
+
+impl ConfirmedTransactionInfo {
+    /// encode the transaction the same way solana rpc does for getTransaction
+    pub fn encode(
+        &self,
+        encoding: UiTransactionEncoding,
+        max_supported_transaction_version: Option<u8>,
+    ) -> anyhow::Result<EncodedConfirmedTransactionWithStatusMeta> {
+        let confirmed_transaction = ConfirmedTransactionWithStatusMeta {
+            slot: self.slot,
+            tx_with_meta: TransactionWithStatusMeta::Complete(
+                self.transaction
+                    .to_versioned_transaction_with_status_meta()?,
+            ),
+            block_time: Some(self.block_time as i64),
+        };
+
+        Ok(confirmed_transaction.encode(encoding, max_supported_transaction_version)?)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use solana_sdk::hash::Hash;
+    use solana_sdk::message::{v0, Message, MessageHeader};
+    use solana_sdk::vote::state::Vote;
+    use solana_transaction_status::option_serializer::OptionSerializer;
+    use solana_transaction_status::{EncodeError, TransactionDetails};
+
+    fn create_test_block(message: VersionedMessage) -> ProducedBlock {
+        ProducedBlock {
+            transactions: vec![TransactionInfo {
+                cu_consumed: Some(3000),
+                message: BASE64.serialize(&message).unwrap(),
+                pre_balances: vec![1_000_000],
+                post_balances: vec![995_000],
+                log_messages: Some(vec!["Program log: test".to_string()]),
+                ..TransactionInfo::new_for_test()
+            }],
+            leader_id: None,
+            blockhash: Hash::new_unique().to_string(),
+            block_height: 42,
+            slot: 1000,
+            parent_slot: 999,
+            block_time: 1699260872,
+            commitment_config: CommitmentConfig::confirmed(),
+            previous_blockhash: Hash::new_unique().to_string(),
+            rewards: None,
+        }
+    }
+
+    #[test]
+    fn encode_legacy_block() {
+        let message = VersionedMessage::Legacy(Message::new(&[], Some(&Pubkey::new_unique())));
+        let block = create_test_block(message);
+
+        let ui_block = block
+            .encode_with_options(
+                UiTransactionEncoding::Base64,
+                BlockEncodingOptions {
+                    transaction_details: TransactionDetails::Full,
+                    show_rewards: false,
+                    max_supported_transaction_version: None,
+                },
+            )
+            .unwrap();
+
+        assert_eq!(ui_block.block_height, Some(42));
+        assert_eq!(ui_block.parent_slot, 999);
+        assert!(ui_block.rewards.is_none());
+        let transactions = ui_block.transactions.unwrap();
+        assert_eq!(transactions.len(), 1);
+        assert!(transactions[0].version.is_none());
+
+        let decoded = transactions[0].transaction.decode().unwrap();
+        assert_eq!(
+            decoded.signatures[0].to_string(),
+            block.transactions[0].signature
+        );
+    }
+
+    #[test]
+    fn encode_every_signature_of_the_transaction() {
+        let signers = [Pubkey::new_unique(), Pubkey::new_unique()];
+        let message = VersionedMessage::Legacy(Message {
+            header: MessageHeader {
+                num_required_signatures: 2,
+                num_readonly_signed_accounts: 0,
+                num_readonly_unsigned_accounts: 0,
+            },
+            account_keys: signers.to_vec(),
+            ..Message::default()
+        });
+        let transaction = TransactionInfo::new_for_test();
+        let signatures = vec![
+            transaction.signature.clone(),
+            Signature::new_unique().to_string(),
+        ];
+        let transaction = TransactionInfo {
+            signatures: signatures.clone(),
+            message: BASE64.serialize(&message).unwrap(),
+            ..transaction
+        };
+
+        let decoded = transaction
+            .to_versioned_transaction_with_status_meta()
+            .unwrap()
+            .transaction;
+        assert_eq!(
+            decoded
+                .signatures
+                .iter()
+                .map(|signature| signature.to_string())
+                .collect::<Vec<_>>(),
+            signatures
+        );
+    }
+
+    #[test]
+    fn encode_v0_block_requires_max_supported_version() {
+        let message = VersionedMessage::V0(v0::Message::default());
+        let block = create_test_block(message);
+
+        let ui_block = block
+            .encode_with_options(
+                UiTransactionEncoding::Json,
+                BlockEncodingOptions {
+                    transaction_details: TransactionDetails::Signatures,
+                    show_rewards: true,
+                    max_supported_transaction_version: None,
+                },
+            )
+            .unwrap();
+        assert_eq!(
+            ui_block.signatures.unwrap(),
+            vec![block.transactions[0].signature.clone()]
+        );
+
+        let err = block
+            .encode_with_options(
+                UiTransactionEncoding::Json,
+                BlockEncodingOptions {
+                    transaction_details: TransactionDetails::Full,
+                    show_rewards: true,
+                    max_supported_transaction_version: None,
+                },
+            )
+            .unwrap_err();
+        assert!(matches!(
+            err.downcast_ref::<EncodeError>(),
+            Some(EncodeError::UnsupportedTransactionVersion(0))
+        ));
+    }
+
+    #[test]
+    fn encode_transaction_with_meta() {
+        let message = VersionedMessage::Legacy(Message::new(&[], Some(&Pubkey::new_unique())));
+        let block = create_test_block(message);
+        let signature = block.transactions[0].signature.clone();
+
+        let encoded = block
+            .get_transaction(&signature)
+            .unwrap()
+            .encode(UiTransactionEncoding::Base58, None)
+            .unwrap();
+        assert_eq!(encoded.slot, 1000);
+        assert_eq!(encoded.block_time, Some(1699260872));
+
+        let meta = encoded.transaction.meta.unwrap();
+        assert_eq!(meta.fee, 5000);
+        assert_eq!(meta.post_balances, vec![995_000]);
+        assert_eq!(
+            meta.log_messages,
+            OptionSerializer::Some(vec!["Program log: test".to_string()])
+        );
+        assert_eq!(
+            encoded.transaction.transaction.decode().unwrap().signatures[0].to_string(),
+            signature
+        );
+
+        assert!(block
+            .get_transaction(&Signature::new_unique().to_string())
+            .is_none());
+    }
+
+    #[test]
+    fn encode_transaction_with_details() {
+        let message = VersionedMessage::Legacy(Message::new(&[], Some(&Pubkey::new_unique())));
+        let block = create_test_block(message);
+        let options = |transaction_details| BlockEncodingOptions {
+            transaction_details,
+            show_rewards: false,
+            max_supported_transaction_version: None,
+        };
+
+        let encoded = block.transactions[0]
+            .encode_with_options(
+                UiTransactionEncoding::Base64,
+                options(TransactionDetails::Full),
+            )
+            .unwrap()
+            .unwrap();
+        assert_eq!(
+            encoded.transaction.decode().unwrap().signatures[0].to_string(),
+            block.transactions[0].signature
+        );
+
+        let encoded = block.transactions[0]
+            .encode_with_options(
+                UiTransactionEncoding::Base64,
+                options(TransactionDetails::Accounts),
+            )
+            .unwrap()
+            .unwrap();
+        assert!(matches!(
+            encoded.transaction,
+            solana_transaction_status::EncodedTransaction::Accounts(_)
+        ));
+
+        assert!(block.transactions[0]
+            .encode_with_options(
+                UiTransactionEncoding::Base64,
+                options(TransactionDetails::Signatures),
+            )
+            .unwrap()
+            .is_none());
+    }
+
+    #[test]
+    fn writable_accounts_include_loaded_addresses() {
+        let payer = Pubkey::new_unique();
+        let readonly = Pubkey::new_unique();
+        let message = VersionedMessage::V0(v0::Message {
+            header: MessageHeader {
+                num_required_signatures: 1,
+                num_readonly_signed_accounts: 0,
+                num_readonly_unsigned_accounts: 1,
+            },
+            account_keys: vec![payer, readonly],
+            ..v0::Message::default()
+        });
+        let loaded_writable = Pubkey::new_unique();
+        let loaded_addresses = LoadedAddresses {
+            writable: vec![loaded_writable],
+            readonly: vec![Pubkey::new_unique()],
+        };
+
+        assert_eq!(
+            TransactionInfo::get_writable_accounts(&message, &loaded_addresses),
+            vec![payer, loaded_writable]
+        );
+    }
+
+    #[test]
+    fn filter_transactions_mentioning_account() {
+        let payer = Pubkey::new_unique();
+        let mut block =
+            create_test_block(VersionedMessage::Legacy(Message::new(&[], Some(&payer))));
+        let other_block = create_test_block(VersionedMessage::Legacy(Message::new(
+            &[],
+            Some(&Pubkey::new_unique()),
+        )));
+        let mut loaded_tx = other_block.transactions[0].clone();
+        let loaded_account = Pubkey::new_unique();
+        loaded_tx.loaded_addresses.readonly.push(loaded_account);
+        block.transactions.push(loaded_tx);
+
+        let filtered = block.with_transactions_mentioning(&payer);
+        assert_eq!(filtered.slot, block.slot);
+        assert_eq!(filtered.transactions.len(), 1);
+        assert_eq!(
+            filtered.transactions[0].signature,
+            block.transactions[0].signature
+        );
+
+        let filtered = block.with_transactions_mentioning(&loaded_account);
+        assert_eq!(filtered.transactions.len(), 1);
+        assert_eq!(
+            filtered.transactions[0].signature,
+            block.transactions[1].signature
+        );
+
+        assert!(block
+            .with_transactions_mentioning(&Pubkey::new_unique())
+            .transactions
+            .is_empty());
+    }
+
+    #[test]
+    fn parse_vote_instructions() {
+        let vote_pubkey = Pubkey::new_unique();
+        let authority = Pubkey::new_unique();
+        let hash = Hash::new_unique();
+        let vote = Vote {
+            slots: vec![98, 99],
+            hash,
+            timestamp: Some(1699260872),
+        };
+        let instruction = solana_sdk::vote::instruction::vote(&vote_pubkey, &authority, vote);
+        let block = create_test_block(VersionedMessage::Legacy(Message::new(
+            &[instruction],
+            Some(&authority),
+        )));
+        let transaction = &block.transactions[0];
+
+        assert_eq!(
+            transaction.get_votes().unwrap(),
+            vec![RpcVote {
+                vote_pubkey: vote_pubkey.to_string(),
+                slots: vec![98, 99],
+                hash: hash.to_string(),
+                timestamp: Some(1699260872),
+                signature: transaction.signature.clone(),
+            }]
+        );
+
+        let no_vote = create_test_block(VersionedMessage::Legacy(Message::new(
+            &[],
+            Some(&authority),
+        )));
+        assert!(no_vote.transactions[0].get_votes().unwrap().is_empty());
+    }
 }
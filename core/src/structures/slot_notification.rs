@@ -1,5 +1,6 @@
 use std::sync::{atomic::AtomicU64, Arc};
 
+use solana_rpc_client_api::response::{SlotInfo, SlotUpdate};
 use solana_sdk::slot_history::Slot;
 
 pub type AtomicSlot = Arc<AtomicU64>;
@@ -8,4 +9,123 @@ pub type AtomicSlot = Arc<AtomicU64>;
 pub struct SlotNotification {
     pub processed_slot: Slot,
     pub estimated_processed_slot: Slot,
+    // parent of processed_slot; None if the source does not provide it
+    pub parent_slot: Option<Slot>,
+    // highest slot optimistically confirmed by the cluster
+    pub confirmed_slot: Slot,
+    // highest rooted (finalized) slot
+    pub root_slot: Slot,
+}
+
+impl SlotNotification {
+    /// slotSubscribe notification; None if processed slot did not advance since the previous notification
+    pub fn slot_info(&self, previous: &SlotNotification) -> Option<SlotInfo> {
+        if self.processed_slot <= previous.processed_slot {
+            return None;
+        }
+
+        Some(SlotInfo {
+            slot: self.processed_slot,
+            // best effort for sources not providing the parent
+            parent: self.parent_slot.unwrap_or(previous.processed_slot),
+            root: self.root_slot,
+        })
+    }
+
+    /// slotUpdatesSubscribe notifications for the changes since the previous notification
+    /// note: firstShredReceived, completed, frozen and dead are not provided by our sources
+    pub fn slot_updates(&self, previous: &SlotNotification, timestamp: u64) -> Vec<SlotUpdate> {
+        let mut updates = vec![];
+        if self.processed_slot > previous.processed_slot {
+            if let Some(parent) = self.parent_slot {
+                updates.push(SlotUpdate::CreatedBank {
+                    slot: self.processed_slot,
+                    parent,
+                    timestamp,
+                });
+            }
+        }
+        if self.confirmed_slot > previous.confirmed_slot {
+            updates.push(SlotUpdate::OptimisticConfirmation {
+                slot: self.confirmed_slot,
+                timestamp,
+            });
+        }
+        if self.root_slot > previous.root_slot {
+            updates.push(SlotUpdate::Root {
+                slot: self.root_slot,
+                timestamp,
+            });
+        }
+        updates
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn slot_updates_since_previous_notification() {
+        let previous = SlotNotification {
+            processed_slot: 100,
+            estimated_processed_slot: 100,
+            parent_slot: Some(99),
+            confirmed_slot: 98,
+            root_slot: 68,
+        };
+
+        // only the root moved
+        let notification = SlotNotification {
+            root_slot: 69,
+            ..previous.clone()
+        };
+        assert_eq!(notification.slot_info(&previous), None);
+        assert_eq!(
+            notification.slot_updates(&previous, 42),
+            vec![SlotUpdate::Root {
+                slot: 69,
+                timestamp: 42
+            }]
+        );
+
+        let notification = SlotNotification {
+            processed_slot: 102,
+            estimated_processed_slot: 102,
+            parent_slot: Some(100),
+            confirmed_slot: 100,
+            root_slot: 68,
+        };
+        assert_eq!(
+            notification.slot_info(&previous),
+            Some(SlotInfo {
+                slot: 102,
+                parent: 100,
+                root: 68
+            })
+        );
+        assert_eq!(
+            notification.slot_updates(&previous, 42),
+            vec![
+                SlotUpdate::CreatedBank {
+                    slot: 102,
+                    parent: 100,
+                    timestamp: 42
+                },
+                SlotUpdate::OptimisticConfirmation {
+                    slot: 100,
+                    timestamp: 42
+                },
+            ]
+        );
+
+        // parent unknown
+        let notification = SlotNotification {
+            processed_slot: 101,
+            parent_slot: None,
+            ..previous.clone()
+        };
+        assert_eq!(notification.slot_info(&previous).unwrap().parent, 100);
+        assert!(notification.slot_updates(&previous, 42).is_empty());
+    }
 }
@@ -0,0 +1,101 @@
+use solana_account_decoder::parse_token::is_known_spl_token_id;
+use solana_sdk::account::ReadableAccount;
+use solana_sdk::pubkey::Pubkey;
+use spl_token_2022::extension::StateWithExtensions;
+use spl_token_2022::state::{Account, Mint};
+
+use crate::structures::account_data::AccountData;
+
+/// fields of an spl token (or token-2022) account
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TokenAccount {
+    pub mint: Pubkey,
+    pub owner: Pubkey,
+    pub delegate: Option<Pubkey>,
+    pub amount: u64,
+}
+
+impl TokenAccount {
+    /// None if the account is not a token account
+    pub fn parse(data: &AccountData) -> Option<Self> {
+        if !is_known_spl_token_id(data.account.owner()) {
+            return None;
+        }
+        let account = StateWithExtensions::<Account>::unpack(data.account.data()).ok()?;
+        Some(Self {
+            mint: account.base.mint,
+            owner: account.base.owner,
+            delegate: account.base.delegate.into(),
+            amount: account.base.amount,
+        })
+    }
+}
+
+/// None if the account is not a mint
+pub fn parse_mint_decimals(data: &AccountData) -> Option<u8> {
+    if !is_known_spl_token_id(data.account.owner()) {
+        return None;
+    }
+    let mint = StateWithExtensions::<Mint>::unpack(data.account.data()).ok()?;
+    Some(mint.base.decimals)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use solana_sdk::account::AccountSharedData;
+    use solana_sdk::program_option::COption;
+    use solana_sdk::program_pack::Pack;
+    use spl_token_2022::state::AccountState;
+
+    #[test]
+    fn parse_token_accounts() {
+        let token_account = Account {
+            mint: Pubkey::new_unique(),
+            owner: Pubkey::new_unique(),
+            amount: 42,
+            delegate: COption::Some(Pubkey::new_unique()),
+            state: AccountState::Initialized,
+            ..Default::default()
+        };
+        let mut data = vec![0; Account::LEN];
+        Account::pack(token_account, &mut data).unwrap();
+        let account_data = |owner: Pubkey, data: Vec<u8>| AccountData {
+            pubkey: Pubkey::new_unique(),
+            account: AccountSharedData::from(solana_sdk::account::Account {
+                lamports: 1,
+                data,
+                owner,
+                executable: false,
+                rent_epoch: 0,
+            }),
+            updated_slot: 1,
+            write_version: 1,
+        };
+
+        assert_eq!(
+            TokenAccount::parse(&account_data(spl_token_2022::id(), data.clone())),
+            Some(TokenAccount {
+                mint: token_account.mint,
+                owner: token_account.owner,
+                delegate: token_account.delegate.into(),
+                amount: 42,
+            })
+        );
+        // not owned by a token program
+        assert!(TokenAccount::parse(&account_data(Pubkey::new_unique(), data.clone())).is_none());
+        assert!(parse_mint_decimals(&account_data(spl_token_2022::id(), data)).is_none());
+
+        let mint = Mint {
+            decimals: 6,
+            is_initialized: true,
+            ..Default::default()
+        };
+        let mut data = vec![0; Mint::LEN];
+        Mint::pack(mint, &mut data).unwrap();
+        assert_eq!(
+            parse_mint_decimals(&account_data(spl_token_2022::id(), data)),
+            Some(6)
+        );
+    }
+}
@@ -0,0 +1,141 @@
+use std::collections::HashSet;
+
+use solana_sdk::pubkey::Pubkey;
+
+use crate::structures::produced_block::TransactionInfo;
+
+/// transaction filter of transactionSubscribe (SIMD-0069); criteria which are not set match every transaction
+#[derive(Debug, Clone, Default)]
+pub struct TransactionFilter {
+    pub vote: Option<bool>,
+    pub failed: Option<bool>,
+    pub signature: Option<String>,
+    // at least one of the accounts has to be mentioned
+    pub account_include: HashSet<Pubkey>,
+    // none of the accounts may be mentioned
+    pub account_exclude: HashSet<Pubkey>,
+    // all the accounts have to be mentioned
+    pub account_required: HashSet<Pubkey>,
+}
+
+impl TransactionFilter {
+    pub fn matches(&self, tx: &TransactionInfo) -> bool {
+        if self.vote.is_some_and(|vote| vote != tx.is_vote) {
+            return false;
+        }
+        if self.failed.is_some_and(|failed| failed != tx.err.is_some()) {
+            return false;
+        }
+        if self
+            .signature
+            .as_ref()
+            .is_some_and(|signature| *signature != tx.signature)
+        {
+            return false;
+        }
+
+        if self.account_include.is_empty()
+            && self.account_exclude.is_empty()
+            && self.account_required.is_empty()
+        {
+            return true;
+        }
+        let Ok(account_keys) = tx.account_keys() else {
+            return false;
+        };
+        let account_keys = account_keys.into_iter().collect::<HashSet<_>>();
+        (self.account_include.is_empty() || !self.account_include.is_disjoint(&account_keys))
+            && self.account_exclude.is_disjoint(&account_keys)
+            && self.account_required.is_subset(&account_keys)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::encoding::BASE64;
+    use solana_sdk::instruction::{AccountMeta, Instruction};
+    use solana_sdk::message::{Message, VersionedMessage};
+    use solana_sdk::transaction::TransactionError;
+
+    fn transaction(accounts: &[Pubkey], is_vote: bool, failed: bool) -> TransactionInfo {
+        let instruction = Instruction::new_with_bytes(
+            Pubkey::new_unique(),
+            &[],
+            accounts
+                .iter()
+                .map(|account| AccountMeta::new_readonly(*account, false))
+                .collect(),
+        );
+        let message =
+            VersionedMessage::Legacy(Message::new(&[instruction], Some(&Pubkey::new_unique())));
+        TransactionInfo {
+            is_vote,
+            err: failed.then_some(TransactionError::AccountInUse),
+            message: BASE64.serialize(&message).unwrap(),
+            ..TransactionInfo::new_for_test()
+        }
+    }
+
+    #[test]
+    fn filter_transactions() {
+        let (a, b, c) = (
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+        );
+        let tx = transaction(&[a, b], false, false);
+        let vote = transaction(&[], true, false);
+        let failed = transaction(&[c], false, true);
+
+        assert!(TransactionFilter::default().matches(&tx));
+        assert!(TransactionFilter::default().matches(&vote));
+
+        let no_votes = TransactionFilter {
+            vote: Some(false),
+            ..Default::default()
+        };
+        assert!(no_votes.matches(&tx));
+        assert!(!no_votes.matches(&vote));
+
+        let only_failed = TransactionFilter {
+            failed: Some(true),
+            ..Default::default()
+        };
+        assert!(!only_failed.matches(&tx));
+        assert!(only_failed.matches(&failed));
+
+        let by_signature = TransactionFilter {
+            signature: Some(tx.signature.clone()),
+            ..Default::default()
+        };
+        assert!(by_signature.matches(&tx));
+        assert!(!by_signature.matches(&failed));
+
+        let include = TransactionFilter {
+            account_include: HashSet::from([b, c]),
+            ..Default::default()
+        };
+        assert!(include.matches(&tx));
+        assert!(include.matches(&failed));
+        assert!(!include.matches(&vote));
+
+        let exclude = TransactionFilter {
+            account_exclude: HashSet::from([c]),
+            ..Default::default()
+        };
+        assert!(exclude.matches(&tx));
+        assert!(!exclude.matches(&failed));
+
+        let required = TransactionFilter {
+            account_required: HashSet::from([a, b]),
+            ..Default::default()
+        };
+        assert!(required.matches(&tx));
+        let required = TransactionFilter {
+            account_required: HashSet::from([a, c]),
+            ..Default::default()
+        };
+        assert!(!required.matches(&tx));
+    }
+}
@@ -4,5 +4,7 @@ use solana_sdk::slot_history::Slot;
 #[async_trait]
 pub trait SubscriptionSink: Send + Sync {
     async fn send(&self, slot: Slot, message: serde_json::Value);
+    /// ends the subscription with an error notification instead of a result
+    fn close_with_error(&self, error: String);
     fn is_closed(&self) -> bool;
 }
@@ -4,7 +4,10 @@ use solana_rpc_client_api::response::{RpcContactInfo, RpcVoteAccountStatus};
 use tokio::sync::broadcast::Receiver;
 
 use crate::{
-    structures::{produced_block::ProducedBlock, slot_notification::SlotNotification},
+    structures::{
+        account_data::AccountNotificationMessage, fork_tree::ReorgNotification,
+        produced_block::ProducedBlock, slot_notification::SlotNotification,
+    },
     traits::subscription_sink::SubscriptionSink,
 };
 
@@ -12,4 +15,6 @@ pub type BlockStream = Receiver<ProducedBlock>;
 pub type SlotStream = Receiver<SlotNotification>;
 pub type VoteAccountStream = Receiver<RpcVoteAccountStatus>;
 pub type ClusterInfoStream = Receiver<Vec<RpcContactInfo>>;
+pub type AccountStream = Receiver<AccountNotificationMessage>;
+pub type ReorgStream = Receiver<ReorgNotification>;
 pub type SubscptionHanderSink = Arc<dyn SubscriptionSink>;
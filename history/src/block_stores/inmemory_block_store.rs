@@ -0,0 +1,249 @@
+use log::trace;
+use solana_lite_rpc_core::structures::produced_block::{ConfirmedTransactionInfo, ProducedBlock};
+use solana_rpc_client_api::response::RpcConfirmedTransactionStatusWithSignature;
+use solana_sdk::commitment_config::CommitmentLevel;
+use solana_sdk::pubkey::Pubkey;
+use solana_sdk::slot_history::Slot;
+use std::collections::BTreeMap;
+use std::ops::{Bound, RangeBounds, RangeInclusive};
+use std::sync::Arc;
+use tokio::sync::RwLock;
+
+/// keep the most recent confirmed and finalized blocks around to serve them before they hit the persistent storage
+#[derive(Clone)]
+pub struct InmemoryBlockStore {
+    block_storage: Arc<RwLock<BTreeMap<Slot, ProducedBlock>>>,
+    number_of_blocks_to_store: usize,
+}
+
+impl InmemoryBlockStore {
+    pub fn new(number_of_blocks_to_store: usize) -> Self {
+        assert!(
+            number_of_blocks_to_store > 0,
+            "must store at least one block"
+        );
+        Self {
+            block_storage: Arc::new(RwLock::new(BTreeMap::new())),
+            number_of_blocks_to_store,
+        }
+    }
+
+    pub async fn save(&self, block: &ProducedBlock) {
+        // processed blocks are not served by getBlock
+        if block.commitment_config.commitment == CommitmentLevel::Processed {
+            return;
+        }
+
+        let mut block_storage = self.block_storage.write().await;
+
+        if let Some(existing) = block_storage.get(&block.slot) {
+            // do not downgrade a finalized block
+            if existing.commitment_config.is_finalized() {
+                return;
+            }
+        }
+
+        block_storage.insert(block.slot, block.clone());
+
+        while block_storage.len() > self.number_of_blocks_to_store {
+            if let Some((evicted_slot, _)) = block_storage.pop_first() {
+                trace!("Evicted block {} from in-memory block store", evicted_slot);
+            }
+        }
+    }
+
+    pub async fn get(&self, slot: Slot) -> Option<ProducedBlock> {
+        self.block_storage.read().await.get(&slot).cloned()
+    }
+
+    /// lookup by signature, newest blocks first
+    pub async fn get_transaction(&self, signature: &str) -> Option<ConfirmedTransactionInfo> {
+        self.block_storage
+            .read()
+            .await
+            .values()
+            .rev()
+            .find_map(|block| block.get_transaction(signature))
+    }
+
+    /// slot and index in its block of a transaction
+    pub async fn get_transaction_position(&self, signature: &str) -> Option<(Slot, usize)> {
+        self.block_storage
+            .read()
+            .await
+            .values()
+            .rev()
+            .find_map(|block| {
+                block
+                    .transactions
+                    .iter()
+                    .position(|tx| tx.signature == signature)
+                    .map(|idx_in_block| (block.slot, idx_in_block))
+            })
+    }
+
+    /// transactions mentioning the account in the slot range, newest first; the before and until
+    /// positions (slot, index in block) are excluded
+    pub async fn get_signatures_for_address(
+        &self,
+        account_key: &Pubkey,
+        slot_range: RangeInclusive<Slot>,
+        before: Option<(Slot, usize)>,
+        until: Option<(Slot, usize)>,
+        limit: usize,
+    ) -> Vec<RpcConfirmedTransactionStatusWithSignature> {
+        // BTreeMap::range panics on inverted ranges
+        if slot_range.is_empty() {
+            return vec![];
+        }
+        let positions = (
+            until.map_or(Bound::Unbounded, Bound::Excluded),
+            before.map_or(Bound::Unbounded, Bound::Excluded),
+        );
+
+        self.block_storage
+            .read()
+            .await
+            .range(slot_range)
+            .rev()
+            .flat_map(|(slot, block)| {
+                block
+                    .transactions
+                    .iter()
+                    .enumerate()
+                    .rev()
+                    .map(move |(idx_in_block, tx)| ((*slot, idx_in_block), block, tx))
+            })
+            .filter(|(position, _, _)| positions.contains(position))
+            .filter(|(_, _, tx)| {
+                tx.account_keys()
+                    .map(|account_keys| account_keys.contains(account_key))
+                    .unwrap_or_default()
+            })
+            .take(limit)
+            .map(
+                |(_, block, tx)| RpcConfirmedTransactionStatusWithSignature {
+                    signature: tx.signature.clone(),
+                    slot: block.slot,
+                    err: tx.err.clone(),
+                    memo: None,
+                    block_time: Some(block.block_time as i64),
+                    confirmation_status: None,
+                },
+            )
+            .collect()
+    }
+
+    pub async fn get_block_slots(&self, slot_range: RangeInclusive<Slot>) -> Vec<Slot> {
+        // BTreeMap::range panics on inverted ranges
+        if slot_range.is_empty() {
+            return vec![];
+        }
+
+        self.block_storage
+            .read()
+            .await
+            .range(slot_range)
+            .map(|(slot, _)| *slot)
+            .collect()
+    }
+
+    pub async fn get_slot_range(&self) -> RangeInclusive<Slot> {
+        let block_storage = self.block_storage.read().await;
+        match (
+            block_storage.first_key_value(),
+            block_storage.last_key_value(),
+        ) {
+            (Some((first, _)), Some((last, _))) => RangeInclusive::new(*first, *last),
+            _ => RangeInclusive::new(1, 0), // empty
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use solana_lite_rpc_core::encoding::BASE64;
+    use solana_lite_rpc_core::structures::produced_block::TransactionInfo;
+    use solana_sdk::commitment_config::CommitmentConfig;
+    use solana_sdk::message::{Message, VersionedMessage};
+
+    fn create_block(slot: Slot, payers: &[Pubkey]) -> ProducedBlock {
+        let transactions = payers
+            .iter()
+            .map(|payer| TransactionInfo {
+                message: BASE64
+                    .serialize(&VersionedMessage::Legacy(Message::new(&[], Some(payer))))
+                    .unwrap(),
+                ..TransactionInfo::new_for_test()
+            })
+            .collect();
+
+        ProducedBlock {
+            transactions,
+            leader_id: None,
+            blockhash: String::new(),
+            block_height: slot,
+            slot,
+            parent_slot: slot - 1,
+            block_time: 1699260872,
+            commitment_config: CommitmentConfig::confirmed(),
+            previous_blockhash: String::new(),
+            rewards: None,
+        }
+    }
+
+    #[tokio::test]
+    async fn signatures_for_address_newest_first() {
+        let store = InmemoryBlockStore::new(10);
+        let (account, other) = (Pubkey::new_unique(), Pubkey::new_unique());
+        let blocks = [
+            create_block(10, &[account, other, account]),
+            create_block(11, &[other, account]),
+        ];
+        for block in &blocks {
+            store.save(block).await;
+        }
+        let signature = |slot: Slot, idx_in_block: usize| {
+            blocks[(slot - 10) as usize].transactions[idx_in_block]
+                .signature
+                .clone()
+        };
+        let signatures = |before, until, limit| {
+            let store = store.clone();
+            async move {
+                store
+                    .get_signatures_for_address(&account, 0..=11, before, until, limit)
+                    .await
+                    .into_iter()
+                    .map(|signature| signature.signature)
+                    .collect::<Vec<_>>()
+            }
+        };
+
+        assert_eq!(
+            signatures(None, None, 10).await,
+            vec![signature(11, 1), signature(10, 2), signature(10, 0)]
+        );
+        assert_eq!(
+            signatures(None, None, 2).await,
+            vec![signature(11, 1), signature(10, 2)]
+        );
+        assert_eq!(
+            signatures(Some((10, 2)), None, 10).await,
+            vec![signature(10, 0)]
+        );
+        assert_eq!(
+            signatures(None, Some((10, 1)), 10).await,
+            vec![signature(11, 1), signature(10, 2)]
+        );
+        assert_eq!(
+            store.get_transaction_position(&signature(11, 1)).await,
+            Some((11, 1))
+        );
+        assert!(store
+            .get_signatures_for_address(&account, RangeInclusive::new(11, 10), None, None, 10)
+            .await
+            .is_empty());
+    }
+}
@@ -1,3 +1,4 @@
 pub mod faithful_block_store;
+pub mod inmemory_block_store;
 pub mod multiple_strategy_block_store;
 pub mod postgres_block_store;
@@ -2,9 +2,14 @@ use crate::block_stores::faithful_block_store::FaithfulBlockStore;
 use crate::block_stores::postgres_block_store::PostgresBlockStore;
 use anyhow::{bail, Context, Result};
 use log::{debug, trace};
-use solana_lite_rpc_core::structures::produced_block::ProducedBlock;
+use solana_lite_rpc_core::structures::produced_block::{ConfirmedTransactionInfo, ProducedBlock};
 use solana_rpc_client::nonblocking::rpc_client::RpcClient;
+use solana_rpc_client_api::response::RpcConfirmedTransactionStatusWithSignature;
+use solana_sdk::pubkey::Pubkey;
+use solana_sdk::signature::Signature;
 use solana_sdk::slot_history::Slot;
+use solana_sdk::transaction::TransactionError;
+use std::collections::HashMap;
 use std::ops::{Deref, RangeInclusive};
 use std::sync::Arc;
 
@@ -75,6 +80,56 @@ impl MultipleStrategyBlockStorage {
         merged
     }
 
+    // slots with blocks in our blockstore; faithful is not considered
+    pub async fn get_block_slots(
+        &self,
+        slot_range: RangeInclusive<Slot>,
+        limit: Option<usize>,
+    ) -> Result<Vec<Slot>> {
+        self.persistent_block_storage
+            .get_block_slots(slot_range, limit)
+            .await
+    }
+
+    // only served from our blockstore
+    pub async fn get_signatures_for_address(
+        &self,
+        account_key: &Pubkey,
+        before: Option<&Signature>,
+        until: Option<&Signature>,
+        highest_slot: Slot,
+        limit: usize,
+    ) -> Result<Vec<RpcConfirmedTransactionStatusWithSignature>> {
+        self.persistent_block_storage
+            .get_signatures_for_address(account_key, before, until, highest_slot, limit)
+            .await
+    }
+
+    // only served from our blockstore
+    pub async fn get_block_time(&self, slot: Slot) -> Result<Option<u64>> {
+        self.persistent_block_storage.get_block_time(slot).await
+    }
+
+    // only served from our blockstore
+    pub async fn get_transaction(
+        &self,
+        signature: &Signature,
+    ) -> Result<Option<ConfirmedTransactionInfo>> {
+        self.persistent_block_storage
+            .get_transaction(signature)
+            .await
+    }
+
+    // only served from our blockstore
+    pub async fn get_transaction_statuses(
+        &self,
+        signatures: &[Signature],
+    ) -> Result<HashMap<Signature, (Slot, Option<TransactionError>)>> {
+        self.persistent_block_storage
+            .get_transaction_statuses(signatures)
+            .await
+    }
+
     // lookup confirmed or finalized block from either our blockstore or faithful
     // TODO find better method name
     pub async fn query_block(
@@ -1,22 +1,31 @@
 use std::collections::HashMap;
 use std::ops::RangeInclusive;
+use std::str::FromStr;
 use std::time::{Duration, Instant};
 
 use anyhow::{bail, Context, Result};
 use itertools::Itertools;
 use log::{debug, info, trace, warn};
+use solana_lite_rpc_core::encoding::BASE64;
 use solana_lite_rpc_core::structures::epoch::EpochRef;
-use solana_lite_rpc_core::structures::{epoch::EpochCache, produced_block::ProducedBlock};
+use solana_lite_rpc_core::structures::{
+    epoch::EpochCache,
+    produced_block::{ConfirmedTransactionInfo, ProducedBlock},
+};
+use solana_rpc_client_api::response::RpcConfirmedTransactionStatusWithSignature;
 use solana_sdk::commitment_config::{CommitmentConfig, CommitmentLevel};
+use solana_sdk::pubkey::Pubkey;
+use solana_sdk::signature::Signature;
 use solana_sdk::slot_history::Slot;
+use solana_sdk::transaction::TransactionError;
 use tokio_postgres::error::SqlState;
 
 use crate::postgres::postgres_config::PostgresSessionConfig;
 use crate::postgres::postgres_epoch::{PostgresEpoch, EPOCH_SCHEMA_PREFIX};
 use crate::postgres::postgres_session::{PostgresSession, PostgresWriteSession};
 use crate::postgres::{
-    postgres_block::PostgresBlock, postgres_session::PostgresSessionCache,
-    postgres_transaction::PostgresTransaction,
+    postgres_account_transaction::PostgresAccountTransaction, postgres_block::PostgresBlock,
+    postgres_session::PostgresSessionCache, postgres_transaction::PostgresTransaction,
 };
 
 const LITERPC_ROLE: &str = "r_literpc";
@@ -108,6 +117,12 @@ impl PostgresBlockStore {
                     "Schema {} for epoch {} already exists - data will be appended",
                     schema_name, epoch
                 );
+                // schemas written by older versions have no account index
+                let statement = PostgresAccountTransaction::build_create_table_statement(epoch);
+                session
+                    .execute_simple(&statement)
+                    .await
+                    .context("create account transactions table for existing epoch")?;
                 return Ok(false);
             } else {
                 return Err(err).context("create schema for new epoch");
@@ -135,6 +150,13 @@ impl PostgresBlockStore {
             .await
             .context("create transaction table for new epoch")?;
 
+        // create account index of transactions
+        let statement = PostgresAccountTransaction::build_create_table_statement(epoch);
+        session
+            .execute_simple(&statement)
+            .await
+            .context("create account transactions table for new epoch")?;
+
         // add foreign key constraint between transactions and blocks
         let statement = PostgresTransaction::build_foreign_key_statement(epoch);
         session
@@ -160,7 +182,22 @@ impl PostgresBlockStore {
 
         matching_range
             .map(|slot_range| slot_range.contains(&slot))
-            .is_some()
+            .unwrap_or(false)
+    }
+
+    /// block time without loading the transactions of the block
+    pub async fn get_block_time(&self, slot: Slot) -> Result<Option<u64>> {
+        let epoch: EpochRef = self.epoch_schedule.get_epoch_at_slot(slot).into();
+        let query = format!(
+            "SELECT block_time FROM {schema}.blocks WHERE slot = {slot}",
+            schema = PostgresEpoch::build_schema_name(epoch),
+            slot = slot,
+        );
+
+        let session = self.get_session().await;
+        let row = session.query_opt(&query, &[]).await?;
+
+        Ok(row.map(|row| row.get::<&str, i64>("block_time") as u64))
     }
 
     pub async fn query(&self, slot: Slot) -> Result<ProducedBlock> {
@@ -168,12 +205,8 @@ impl PostgresBlockStore {
         let epoch: EpochRef = self.epoch_schedule.get_epoch_at_slot(slot).into();
 
         let query = PostgresBlock::build_query_statement(epoch, slot);
-        let block_row = self
-            .get_session()
-            .await
-            .query_opt(&query, &[])
-            .await
-            .unwrap();
+        let session = self.get_session().await;
+        let block_row = session.query_opt(&query, &[]).await?;
 
         if block_row.is_none() {
             bail!("Block {} in epoch {} not found in postgres", slot, epoch);
@@ -204,11 +237,12 @@ impl PostgresBlockStore {
             leader_id,
         };
 
-        let produced_block = postgres_block.into_produced_block(
-            // TODO what to do
-            vec![],
-            CommitmentConfig::confirmed(),
-        );
+        let transaction_infos = PostgresTransaction::get(&session, epoch, slot as Slot)
+            .await
+            .context(format!("load transactions of block {}", slot))?;
+
+        let produced_block =
+            postgres_block.into_produced_block(transaction_infos, CommitmentConfig::confirmed());
 
         debug!(
             "Querying produced block {} from postgres in epoch schema {} took {:.2}ms: {}/{}",
@@ -246,7 +280,8 @@ impl PostgresBlockStore {
         let transactions = block
             .transactions
             .iter()
-            .map(|x| PostgresTransaction::new(x, slot))
+            .enumerate()
+            .map(|(idx, x)| PostgresTransaction::new(x, slot, idx))
             .collect_vec();
         let postgres_block = PostgresBlock::from(block);
 
@@ -287,14 +322,43 @@ impl PostgresBlockStore {
 
         let elapsed_txs_insert = started_txs.elapsed();
 
+        let started_account_index = Instant::now();
+        let account_transactions = block
+            .transactions
+            .iter()
+            .enumerate()
+            .flat_map(|(idx, x)| PostgresAccountTransaction::from_transaction(x, slot, idx))
+            .collect_vec();
+
+        let mut queries_fut = Vec::new();
+        let index_chunk_size = div_ceil(account_transactions.len(), self.write_sessions.len())
+            .max(MIN_WRITE_CHUNK_SIZE);
+        for (i, chunk) in account_transactions.chunks(index_chunk_size).enumerate() {
+            let session = self.write_sessions[i].get_write_session().await.clone();
+            let future = PostgresAccountTransaction::save_account_transactions_copyin(
+                session,
+                epoch.into(),
+                chunk,
+            );
+            queries_fut.push(future);
+        }
+        let all_results: Vec<Result<bool>> = futures_util::future::join_all(queries_fut).await;
+        for result in all_results {
+            result.context("save account index of transactions")?;
+        }
+
+        let elapsed_account_index_insert = started_account_index.elapsed();
+
         debug!(
-            "Saving block {} to postgres took {:.2}ms for block and {:.2}ms for {} transactions ({}x{} chunks)",
+            "Saving block {} to postgres took {:.2}ms for block, {:.2}ms for {} transactions ({}x{} chunks) and {:.2}ms for {} account index entries",
             slot,
             elapsed_block_insert.as_secs_f64() * 1000.0,
             elapsed_txs_insert.as_secs_f64() * 1000.0,
             transactions.len(),
             chunks.len(),
             chunk_size,
+            elapsed_account_index_insert.as_secs_f64() * 1000.0,
+            account_transactions.len(),
         );
 
         Ok(())
@@ -369,6 +433,10 @@ impl PostgresBlockStore {
     pub async fn get_slot_range(&self) -> RangeInclusive<Slot> {
         let map_epoch_to_slot_range = self.get_slot_range_by_epoch().await;
 
+        if map_epoch_to_slot_range.is_empty() {
+            return RangeInclusive::new(1, 0); // empty
+        }
+
         let rows_minmax: Vec<&RangeInclusive<Slot>> =
             map_epoch_to_slot_range.values().collect_vec();
 
@@ -389,39 +457,18 @@ impl PostgresBlockStore {
     pub async fn get_slot_range_by_epoch(&self) -> HashMap<EpochRef, RangeInclusive<Slot>> {
         let started = Instant::now();
         let session = self.get_session().await;
-        // e.g. "rpc2a_epoch_552"
-        let query = format!(
-            r#"
-                SELECT
-                 schema_name
-                FROM information_schema.schemata
-                WHERE schema_name ~ '^{schema_prefix}[0-9]+$'
-            "#,
-            schema_prefix = EPOCH_SCHEMA_PREFIX
-        );
-        let result = session.query_list(&query, &[]).await.unwrap();
+        let epochs = Self::get_existing_epochs(&session).await.unwrap();
 
-        let epoch_schemas = result
-            .iter()
-            .map(|row| row.get::<&str, &str>("schema_name"))
-            .map(|schema_name| {
-                (
-                    schema_name,
-                    PostgresEpoch::parse_epoch_from_schema_name(schema_name),
-                )
-            })
-            .collect_vec();
-
-        if epoch_schemas.is_empty() {
+        if epochs.is_empty() {
             return HashMap::new();
         }
 
-        let inner = epoch_schemas
+        let inner = epochs
             .iter()
-            .map(|(schema, epoch)| {
+            .map(|epoch| {
                 format!(
                     "SELECT slot,{epoch}::bigint as epoch FROM {schema}.blocks",
-                    schema = schema,
+                    schema = PostgresEpoch::build_schema_name(*epoch),
                     epoch = epoch
                 )
             })
@@ -480,6 +527,322 @@ impl PostgresBlockStore {
 
         final_range
     }
+
+    // epochs for which a schema exists, e.g. "rpc2a_epoch_552"
+    async fn get_existing_epochs(session: &PostgresSession) -> Result<Vec<EpochRef>> {
+        let query = format!(
+            r#"
+                SELECT
+                 schema_name
+                FROM information_schema.schemata
+                WHERE schema_name ~ '^{schema_prefix}[0-9]+$'
+            "#,
+            schema_prefix = EPOCH_SCHEMA_PREFIX
+        );
+        let result = session.query_list(&query, &[]).await?;
+
+        Ok(result
+            .iter()
+            .map(|row| row.get::<&str, &str>("schema_name"))
+            .map(PostgresEpoch::parse_epoch_from_schema_name)
+            .sorted()
+            .collect_vec())
+    }
+
+    /// signatures of transactions using the account, newest first like solana rpc does;
+    /// confirmation_status is not set as the block store does not know about commitment levels
+    pub async fn get_signatures_for_address(
+        &self,
+        account_key: &Pubkey,
+        before: Option<&Signature>,
+        until: Option<&Signature>,
+        highest_slot: Slot,
+        limit: usize,
+    ) -> Result<Vec<RpcConfirmedTransactionStatusWithSignature>> {
+        let started = Instant::now();
+        let session = self.get_session().await;
+        let epochs = Self::get_existing_epochs(&session).await?;
+
+        let before_position = match before {
+            Some(before) => {
+                match Self::get_transaction_position(&session, &epochs, before).await? {
+                    Some(position) => Some(position),
+                    // same as solana: nothing older than an unknown signature
+                    None => return Ok(vec![]),
+                }
+            }
+            None => None,
+        };
+        let until_position = match until {
+            Some(until) => Self::get_transaction_position(&session, &epochs, until).await?,
+            None => None,
+        };
+
+        let mut conditions = vec![format!("acc_tx.slot <= {}", highest_slot)];
+        let mut max_slot = highest_slot;
+        let mut min_slot = 0;
+        if let Some((slot, idx_in_block)) = before_position {
+            conditions.push(format!(
+                "(acc_tx.slot, acc_tx.idx_in_block) < ({}, {})",
+                slot, idx_in_block
+            ));
+            max_slot = max_slot.min(slot);
+        }
+        if let Some((slot, idx_in_block)) = until_position {
+            conditions.push(format!(
+                "(acc_tx.slot, acc_tx.idx_in_block) > ({}, {})",
+                slot, idx_in_block
+            ));
+            min_slot = slot;
+        }
+
+        let min_epoch: EpochRef = self.epoch_schedule.get_epoch_at_slot(min_slot).into();
+        let max_epoch: EpochRef = self.epoch_schedule.get_epoch_at_slot(max_slot).into();
+        let epochs = epochs
+            .into_iter()
+            .filter(|epoch| *epoch >= min_epoch && *epoch <= max_epoch)
+            .collect_vec();
+
+        if epochs.is_empty() {
+            return Ok(vec![]);
+        }
+
+        let conditions = conditions.join(" AND ");
+        let inner = epochs
+            .iter()
+            .map(|epoch| {
+                format!(
+                    r#"
+                        SELECT acc_tx.signature, acc_tx.slot, acc_tx.idx_in_block, tx.err, blocks.block_time
+                        FROM {schema}.account_transactions AS acc_tx
+                        INNER JOIN {schema}.transactions AS tx ON tx.signature = acc_tx.signature
+                        INNER JOIN {schema}.blocks AS blocks ON blocks.slot = acc_tx.slot
+                        WHERE acc_tx.account_key = $1 AND {conditions}
+                    "#,
+                    schema = PostgresEpoch::build_schema_name(*epoch),
+                    conditions = conditions,
+                )
+            })
+            .join(" UNION ALL ");
+
+        let query = format!(
+            r#"
+                SELECT signature, slot, err, block_time FROM (
+                    {inner}
+                ) AS all_signatures
+                ORDER BY slot DESC, idx_in_block DESC
+                LIMIT {limit}
+            "#,
+            inner = inner,
+            limit = limit,
+        );
+
+        let rows = session
+            .query_list(&query, &[&account_key.to_string()])
+            .await?;
+
+        let signatures = rows
+            .iter()
+            .map(|row| {
+                let err: Option<String> = row.get("err");
+                let err = err
+                    .map(|err| BASE64.deserialize::<TransactionError>(&err))
+                    .transpose()?;
+
+                Ok(RpcConfirmedTransactionStatusWithSignature {
+                    signature: row.get("signature"),
+                    slot: row.get::<&str, i64>("slot") as Slot,
+                    err,
+                    memo: None,
+                    block_time: Some(row.get::<&str, i64>("block_time")),
+                    confirmation_status: None,
+                })
+            })
+            .collect::<Result<Vec<_>>>()?;
+
+        debug!(
+            "Query of {} signatures for address {} in {} epoch schemas took {:.2}ms",
+            signatures.len(),
+            account_key,
+            epochs.len(),
+            started.elapsed().as_secs_f64() * 1000.0
+        );
+
+        Ok(signatures)
+    }
+
+    // slot and position in block of a transaction
+    async fn get_transaction_position(
+        session: &PostgresSession,
+        epochs: &[EpochRef],
+        signature: &Signature,
+    ) -> Result<Option<(Slot, i32)>> {
+        if epochs.is_empty() {
+            return Ok(None);
+        }
+
+        let inner = epochs
+            .iter()
+            .map(|epoch| {
+                format!(
+                    "SELECT slot, idx_in_block FROM {schema}.transactions WHERE signature = $1",
+                    schema = PostgresEpoch::build_schema_name(*epoch),
+                )
+            })
+            .join(" UNION ALL ");
+        let query = format!("{} LIMIT 1", inner);
+
+        let row = session.query_opt(&query, &[&signature.to_string()]).await?;
+
+        Ok(row.map(|row| {
+            (
+                row.get::<&str, i64>("slot") as Slot,
+                row.get::<&str, i32>("idx_in_block"),
+            )
+        }))
+    }
+
+    /// transaction with its status meta; the newest epochs are searched first
+    pub async fn get_transaction(
+        &self,
+        signature: &Signature,
+    ) -> Result<Option<ConfirmedTransactionInfo>> {
+        let started = Instant::now();
+        let session = self.get_session().await;
+        let signature = signature.to_string();
+
+        for epoch in Self::get_existing_epochs(&session).await?.into_iter().rev() {
+            let transaction =
+                PostgresTransaction::get_by_signature(&session, epoch, &signature).await?;
+            if transaction.is_some() {
+                debug!(
+                    "Query of transaction {} took {:.2}ms",
+                    signature,
+                    started.elapsed().as_secs_f64() * 1000.0
+                );
+                return Ok(transaction);
+            }
+        }
+
+        Ok(None)
+    }
+
+    /// slot and error of the stored transactions among the signatures, searched across all epochs
+    pub async fn get_transaction_statuses(
+        &self,
+        signatures: &[Signature],
+    ) -> Result<HashMap<Signature, (Slot, Option<TransactionError>)>> {
+        let started = Instant::now();
+        let session = self.get_session().await;
+        let epochs = Self::get_existing_epochs(&session).await?;
+        if epochs.is_empty() || signatures.is_empty() {
+            return Ok(HashMap::new());
+        }
+
+        let query = epochs
+            .iter()
+            .map(|epoch| {
+                format!(
+                    "SELECT signature, slot, err FROM {schema}.transactions WHERE signature = ANY($1)",
+                    schema = PostgresEpoch::build_schema_name(*epoch),
+                )
+            })
+            .join(" UNION ALL ");
+        let signature_strs = signatures.iter().map(Signature::to_string).collect_vec();
+        let rows = session.query_list(&query, &[&signature_strs]).await?;
+
+        let mut statuses = HashMap::new();
+        for row in rows {
+            let signature = Signature::from_str(row.get::<&str, &str>("signature"))
+                .context("invalid signature in transactions table")?;
+            let err = row
+                .get::<&str, Option<String>>("err")
+                .map(|err| BASE64.deserialize::<TransactionError>(&err))
+                .transpose()?;
+            statuses.insert(signature, (row.get::<&str, i64>("slot") as Slot, err));
+        }
+        debug!(
+            "Query of {} transaction statuses took {:.2}ms",
+            signatures.len(),
+            started.elapsed().as_secs_f64() * 1000.0
+        );
+
+        Ok(statuses)
+    }
+
+    /// slots with a stored block in the given range, ascending; skipped slots are not included
+    pub async fn get_block_slots(
+        &self,
+        slot_range: RangeInclusive<Slot>,
+        limit: Option<usize>,
+    ) -> Result<Vec<Slot>> {
+        if slot_range.is_empty() {
+            return Ok(vec![]);
+        }
+
+        let started = Instant::now();
+        let session = self.get_session().await;
+
+        let first_epoch: EpochRef = self
+            .epoch_schedule
+            .get_epoch_at_slot(*slot_range.start())
+            .into();
+        let last_epoch: EpochRef = self
+            .epoch_schedule
+            .get_epoch_at_slot(*slot_range.end())
+            .into();
+
+        let epochs = Self::get_existing_epochs(&session)
+            .await?
+            .into_iter()
+            .filter(|epoch| *epoch >= first_epoch && *epoch <= last_epoch)
+            .collect_vec();
+
+        if epochs.is_empty() {
+            return Ok(vec![]);
+        }
+
+        let inner = epochs
+            .iter()
+            .map(|epoch| {
+                format!(
+                    "SELECT slot FROM {schema}.blocks WHERE slot >= {from_slot} AND slot <= {to_slot}",
+                    schema = PostgresEpoch::build_schema_name(*epoch),
+                    from_slot = slot_range.start(),
+                    to_slot = slot_range.end(),
+                )
+            })
+            .join(" UNION ALL ");
+
+        let query = format!(
+            r#"
+                SELECT slot FROM (
+                    {inner}
+                ) AS all_slots
+                ORDER BY slot
+                {limit}
+            "#,
+            inner = inner,
+            limit = limit
+                .map(|limit| format!("LIMIT {}", limit))
+                .unwrap_or_default(),
+        );
+
+        let rows = session.query_list(&query, &[]).await?;
+        let slots = rows
+            .iter()
+            .map(|row| row.get::<&str, i64>("slot") as Slot)
+            .collect_vec();
+
+        debug!(
+            "Listing {} block slots in range {:?} from postgres took {:.2}ms",
+            slots.len(),
+            slot_range,
+            started.elapsed().as_secs_f64() * 1000.0
+        );
+
+        Ok(slots)
+    }
 }
 
 #[cfg(test)]
@@ -551,13 +914,13 @@ mod tests {
     fn create_test_tx(signature: Signature) -> TransactionInfo {
         TransactionInfo {
             signature: signature.to_string(),
-            is_vote: false,
-            err: None,
+            signatures: vec![signature.to_string()],
             cu_requested: Some(40000),
             prioritization_fees: Some(5000),
             cu_consumed: Some(32000),
             recent_blockhash: "recent_blockhash".to_string(),
             message: "some message".to_string(),
+            ..TransactionInfo::new_for_test()
         }
     }
 }
@@ -1,10 +1,287 @@
+use std::collections::HashMap;
+use std::ops::RangeInclusive;
+use std::sync::Arc;
+
+use log::{debug, warn};
+use solana_lite_rpc_core::structures::produced_block::{ConfirmedTransactionInfo, ProducedBlock};
+use solana_lite_rpc_core::types::BlockStream;
+use solana_lite_rpc_core::AnyhowJoinHandle;
+use solana_rpc_client_api::response::RpcConfirmedTransactionStatusWithSignature;
+use solana_sdk::pubkey::Pubkey;
+use solana_sdk::signature::Signature;
+use solana_sdk::slot_history::Slot;
+use solana_sdk::transaction::TransactionError;
+use tokio::sync::broadcast::error::RecvError;
+
+use crate::block_stores::inmemory_block_store::InmemoryBlockStore;
+use crate::block_stores::multiple_strategy_block_store::MultipleStrategyBlockStorage;
+
+// roughly 2 minutes of blocks
+pub const NB_BLOCKS_TO_KEEP_IN_MEMORY: usize = 300;
+
+#[derive(Clone)]
 pub struct History {
-    // Placeholder
+    // recent confirmed and finalized blocks from the block stream
+    pub inmemory_block_storage: InmemoryBlockStore,
+    // optional; blocks persisted to postgres (and faithful)
+    pub block_storage: Option<Arc<MultipleStrategyBlockStorage>>,
 }
 
 impl History {
     pub fn new() -> Self {
-        History {}
+        History {
+            inmemory_block_storage: InmemoryBlockStore::new(NB_BLOCKS_TO_KEEP_IN_MEMORY),
+            block_storage: None,
+        }
+    }
+
+    pub fn new_with_block_storage(block_storage: MultipleStrategyBlockStorage) -> Self {
+        History {
+            block_storage: Some(Arc::new(block_storage)),
+            ..Self::new()
+        }
+    }
+
+    /// feed the in-memory block store from the block stream
+    pub fn start_block_listener(&self, mut block_stream: BlockStream) -> AnyhowJoinHandle {
+        let inmemory_block_storage = self.inmemory_block_storage.clone();
+        tokio::spawn(async move {
+            loop {
+                match block_stream.recv().await {
+                    Ok(block) => {
+                        inmemory_block_storage.save(&block).await;
+                    }
+                    Err(RecvError::Lagged(missed_blocks)) => {
+                        warn!(
+                            "History block listener lagged - missed {} blocks",
+                            missed_blocks
+                        );
+                    }
+                    Err(RecvError::Closed) => {
+                        anyhow::bail!("Block stream closed unexpectedly");
+                    }
+                }
+            }
+        })
+    }
+
+    /// slots range served by in-memory and persistent block storage combined
+    pub async fn get_slot_range(&self) -> RangeInclusive<Slot> {
+        let inmemory_range = self.inmemory_block_storage.get_slot_range().await;
+
+        let Some(block_storage) = &self.block_storage else {
+            return inmemory_range;
+        };
+        let persistent_range = block_storage.get_slot_range().await;
+
+        match (inmemory_range.is_empty(), persistent_range.is_empty()) {
+            (true, _) => persistent_range,
+            (false, true) => inmemory_range,
+            (false, false) => RangeInclusive::new(
+                *inmemory_range.start().min(persistent_range.start()),
+                *inmemory_range.end().max(persistent_range.end()),
+            ),
+        }
+    }
+
+    /// confirmed or finalized block; None if the slot is not available
+    pub async fn get_block(&self, slot: Slot) -> Option<ProducedBlock> {
+        if !self.get_slot_range().await.contains(&slot) {
+            debug!("Block {} is outside of the history slot range", slot);
+            return None;
+        }
+
+        if let Some(block) = self.inmemory_block_storage.get(slot).await {
+            return Some(block);
+        }
+
+        let block_storage = self.block_storage.as_ref()?;
+        match block_storage.query_block(slot).await {
+            Ok(block_storage_data) => Some(block_storage_data.block),
+            Err(err) => {
+                debug!("Block {} not found in block storage: {:?}", slot, err);
+                None
+            }
+        }
+    }
+
+    /// block time (unix timestamp in seconds); None if the slot is not available
+    pub async fn get_block_time(&self, slot: Slot) -> Option<u64> {
+        if !self.get_slot_range().await.contains(&slot) {
+            debug!("Block {} is outside of the history slot range", slot);
+            return None;
+        }
+
+        if let Some(block) = self.inmemory_block_storage.get(slot).await {
+            return Some(block.block_time);
+        }
+
+        let block_storage = self.block_storage.as_ref()?;
+        match block_storage.get_block_time(slot).await {
+            Ok(block_time) => block_time,
+            Err(err) => {
+                debug!("Block time {} not found in block storage: {:?}", slot, err);
+                None
+            }
+        }
+    }
+
+    /// lowest slot served by the history; None if no block is available yet
+    pub async fn get_first_available_block(&self) -> Option<Slot> {
+        let slot_range = self.get_slot_range().await;
+        if slot_range.is_empty() {
+            None
+        } else {
+            Some(*slot_range.start())
+        }
+    }
+
+    /// slots of confirmed or finalized blocks in range (ascending); skipped slots are not included
+    pub async fn get_block_slots(
+        &self,
+        slot_range: RangeInclusive<Slot>,
+        limit: Option<usize>,
+    ) -> anyhow::Result<Vec<Slot>> {
+        let mut slots = self
+            .inmemory_block_storage
+            .get_block_slots(slot_range.clone())
+            .await;
+
+        if let Some(block_storage) = &self.block_storage {
+            slots.extend(block_storage.get_block_slots(slot_range, limit).await?);
+            slots.sort_unstable();
+            slots.dedup();
+        }
+
+        if let Some(limit) = limit {
+            slots.truncate(limit);
+        }
+
+        Ok(slots)
+    }
+
+    /// confirmed or finalized transaction; recent blocks are served from memory before they are persisted
+    pub async fn get_transaction(
+        &self,
+        signature: &Signature,
+    ) -> anyhow::Result<Option<ConfirmedTransactionInfo>> {
+        if let Some(transaction) = self
+            .inmemory_block_storage
+            .get_transaction(&signature.to_string())
+            .await
+        {
+            return Ok(Some(transaction));
+        }
+
+        match &self.block_storage {
+            Some(block_storage) => block_storage.get_transaction(signature).await,
+            None => Ok(None),
+        }
+    }
+
+    /// slot and error of the confirmed or finalized transactions among the signatures
+    pub async fn get_transaction_statuses(
+        &self,
+        signatures: &[Signature],
+    ) -> anyhow::Result<HashMap<Signature, (Slot, Option<TransactionError>)>> {
+        let mut statuses = HashMap::new();
+        let mut not_in_memory = vec![];
+        for signature in signatures {
+            match self
+                .inmemory_block_storage
+                .get_transaction(&signature.to_string())
+                .await
+            {
+                Some(transaction) => {
+                    statuses.insert(*signature, (transaction.slot, transaction.transaction.err));
+                }
+                None => not_in_memory.push(*signature),
+            }
+        }
+
+        if let Some(block_storage) = &self.block_storage {
+            statuses.extend(
+                block_storage
+                    .get_transaction_statuses(&not_in_memory)
+                    .await?,
+            );
+        }
+
+        Ok(statuses)
+    }
+
+    /// newest first; blocks which are not persisted yet are served from memory
+    pub async fn get_signatures_for_address(
+        &self,
+        account_key: &Pubkey,
+        before: Option<&Signature>,
+        until: Option<&Signature>,
+        highest_slot: Slot,
+        limit: usize,
+    ) -> anyhow::Result<Vec<RpcConfirmedTransactionStatusWithSignature>> {
+        let persisted_last_slot = match &self.block_storage {
+            Some(block_storage) => {
+                let persistent_range = block_storage.get_slot_range().await;
+                (!persistent_range.is_empty()).then(|| *persistent_range.end())
+            }
+            None => None,
+        };
+        let inmemory_slots =
+            RangeInclusive::new(persisted_last_slot.map_or(0, |slot| slot + 1), highest_slot);
+
+        let before_position = self.get_inmemory_position(before, &inmemory_slots).await;
+        let until_position = self.get_inmemory_position(until, &inmemory_slots).await;
+
+        // a persisted before signature excludes all the blocks kept in memory
+        let mut signatures = if before.is_some() && before_position.is_none() {
+            vec![]
+        } else {
+            self.inmemory_block_storage
+                .get_signatures_for_address(
+                    account_key,
+                    inmemory_slots,
+                    before_position,
+                    until_position,
+                    limit,
+                )
+                .await
+        };
+
+        let (Some(block_storage), Some(persisted_last_slot)) =
+            (&self.block_storage, persisted_last_slot)
+        else {
+            return Ok(signatures);
+        };
+        if until_position.is_some() || signatures.len() >= limit {
+            return Ok(signatures);
+        }
+
+        let before = before.filter(|_| before_position.is_none());
+        signatures.extend(
+            block_storage
+                .get_signatures_for_address(
+                    account_key,
+                    before,
+                    until,
+                    highest_slot.min(persisted_last_slot),
+                    limit - signatures.len(),
+                )
+                .await?,
+        );
+
+        Ok(signatures)
+    }
+
+    // position of a transaction of the blocks in the slot range kept in memory
+    async fn get_inmemory_position(
+        &self,
+        signature: Option<&Signature>,
+        slot_range: &RangeInclusive<Slot>,
+    ) -> Option<(Slot, usize)> {
+        self.inmemory_block_storage
+            .get_transaction_position(&signature?.to_string())
+            .await
+            .filter(|(slot, _)| slot_range.contains(slot))
     }
 }
 
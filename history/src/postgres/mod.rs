@@ -1,6 +1,7 @@
 pub mod postgres_config;
 pub mod postgres_session;
 
+pub mod postgres_account_transaction;
 pub mod postgres_block;
 pub mod postgres_epoch;
 pub mod postgres_transaction;
@@ -0,0 +1,105 @@
+use crate::postgres::postgres_epoch::PostgresEpoch;
+use bytes::Bytes;
+use futures_util::pin_mut;
+use itertools::Itertools;
+use log::warn;
+use solana_lite_rpc_core::structures::epoch::EpochRef;
+use solana_lite_rpc_core::structures::produced_block::TransactionInfo;
+use solana_sdk::slot_history::Slot;
+use tokio_postgres::binary_copy::BinaryCopyInWriter;
+use tokio_postgres::types::Type;
+use tokio_postgres::CopyInSink;
+
+use super::postgres_session::PostgresSession;
+
+/// index entry: account (static or loaded from address lookup table) was used by transaction
+#[derive(Debug)]
+pub struct PostgresAccountTransaction {
+    pub account_key: String,
+    pub signature: String,
+    pub slot: i64,
+    pub idx_in_block: i32,
+}
+
+impl PostgresAccountTransaction {
+    pub fn from_transaction(value: &TransactionInfo, slot: Slot, idx_in_block: usize) -> Vec<Self> {
+        let account_keys = match value.account_keys() {
+            Ok(account_keys) => account_keys,
+            Err(err) => {
+                warn!(
+                    "Cannot index accounts of transaction {} - skipping: {:?}",
+                    value.signature, err
+                );
+                return vec![];
+            }
+        };
+
+        account_keys
+            .into_iter()
+            .unique()
+            .map(|account_key| Self {
+                account_key: account_key.to_string(),
+                signature: value.signature.clone(),
+                slot: slot as i64,
+                idx_in_block: idx_in_block as i32,
+            })
+            .collect_vec()
+    }
+
+    pub fn build_create_table_statement(epoch: EpochRef) -> String {
+        let schema = PostgresEpoch::build_schema_name(epoch);
+        format!(
+            r#"
+                CREATE TABLE IF NOT EXISTS {schema}.account_transactions (
+                    account_key VARCHAR(44) NOT NULL,
+                    signature VARCHAR(88) NOT NULL,
+                    slot BIGINT NOT NULL,
+                    idx_in_block INT NOT NULL,
+                    CONSTRAINT pk_account_transactions PRIMARY KEY(account_key, slot, idx_in_block)
+                  ) WITH (FILLFACTOR=90);
+            "#,
+            schema = schema
+        )
+    }
+
+    // this version uses "COPY IN"
+    pub async fn save_account_transactions_copyin(
+        postgres_session: PostgresSession,
+        epoch: EpochRef,
+        account_transactions: &[Self],
+    ) -> anyhow::Result<bool> {
+        let schema = PostgresEpoch::build_schema_name(epoch);
+        let statement = format!(
+            r#"
+                COPY {schema}.account_transactions(
+                    account_key, signature, slot, idx_in_block
+                ) FROM STDIN BINARY
+            "#,
+            schema = schema,
+        );
+
+        let sink: CopyInSink<Bytes> = postgres_session.copy_in(&statement).await?;
+
+        let writer =
+            BinaryCopyInWriter::new(sink, &[Type::TEXT, Type::TEXT, Type::INT8, Type::INT4]);
+        pin_mut!(writer);
+
+        for account_transaction in account_transactions {
+            let PostgresAccountTransaction {
+                account_key,
+                signature,
+                slot,
+                idx_in_block,
+            } = account_transaction;
+
+            writer
+                .as_mut()
+                .write(&[&account_key, &signature, &slot, &idx_in_block])
+                .await?;
+        }
+
+        writer.finish().await?;
+
+        Ok(true)
+    }
+}
@@ -58,9 +58,8 @@ impl PostgresBlock {
             .unwrap_or(None);
 
         ProducedBlock {
-            // TODO implement
             transactions: transaction_infos,
-            leader_id: None,
+            leader_id: self.leader_id.clone(),
             blockhash: self.blockhash.clone(),
             block_height: self.block_height as u64,
             slot: self.slot as Slot,
@@ -210,7 +209,10 @@ mod tests {
             leader_id: None,
         };
 
-        let transaction_infos = vec![create_tx_info(), create_tx_info()];
+        let transaction_infos = vec![
+            TransactionInfo::new_for_test(),
+            TransactionInfo::new_for_test(),
+        ];
 
         let produced_block =
             block.into_produced_block(transaction_infos, CommitmentConfig::confirmed());
@@ -218,17 +220,4 @@ mod tests {
         assert_eq!(produced_block.slot, 5050505);
         assert_eq!(produced_block.transactions.len(), 2);
     }
-
-    fn create_tx_info() -> TransactionInfo {
-        TransactionInfo {
-            signature: "signature".to_string(),
-            is_vote: false,
-            err: None,
-            cu_requested: None,
-            prioritization_fees: None,
-            cu_consumed: None,
-            recent_blockhash: "recent_blockhash".to_string(),
-            message: "message".to_string(),
-        }
-    }
 }
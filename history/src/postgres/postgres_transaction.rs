@@ -3,30 +3,55 @@ use bytes::Bytes;
 use futures_util::pin_mut;
 use log::{trace, warn};
 use solana_lite_rpc_core::structures::epoch::EpochRef;
-use solana_lite_rpc_core::{encoding::BASE64, structures::produced_block::TransactionInfo};
+use solana_lite_rpc_core::{
+    encoding::BASE64,
+    structures::produced_block::{ConfirmedTransactionInfo, TransactionInfo},
+};
+use solana_sdk::message::v0::LoadedAddresses;
+use solana_sdk::message::VersionedMessage;
 use solana_sdk::slot_history::Slot;
+use solana_sdk::transaction::TransactionError;
+use solana_sdk::transaction_context::TransactionReturnData;
+use solana_transaction_status::InnerInstructions;
 use tokio_postgres::binary_copy::BinaryCopyInWriter;
 use tokio_postgres::types::{ToSql, Type};
-use tokio_postgres::CopyInSink;
+use tokio_postgres::{CopyInSink, Row};
 
 use super::postgres_session::PostgresSession;
 
 #[derive(Debug)]
 pub struct PostgresTransaction {
     pub signature: String,
+    // every signature of the transaction, including `signature`
+    pub signatures: Vec<String>,
     pub slot: i64,
+    // position of the transaction in the block
+    pub idx_in_block: i32,
     pub err: Option<String>,
     pub cu_requested: Option<i64>,
     pub prioritization_fees: Option<i64>,
     pub cu_consumed: Option<i64>,
     pub recent_blockhash: String,
     pub message: String,
+    // transaction status meta
+    pub fee: i64,
+    pub pre_balances: Vec<i64>,
+    pub post_balances: Vec<i64>,
+    pub inner_instructions: Option<String>,
+    pub log_messages: Option<Vec<String>>,
+    pub loaded_addresses: String,
+    pub return_data: Option<String>,
 }
 
 impl PostgresTransaction {
-    pub fn new(value: &TransactionInfo, slot: Slot) -> Self {
+    const SELECT_COLUMNS: &'static str = "signature, signatures, err, cu_requested, prioritization_fees, cu_consumed, recent_blockhash, message, \
+        fee, pre_balances, post_balances, inner_instructions, log_messages, loaded_addresses, return_data";
+
+    pub fn new(value: &TransactionInfo, slot: Slot, idx_in_block: usize) -> Self {
         Self {
             signature: value.signature.clone(),
+            signatures: value.signatures.clone(),
+            idx_in_block: idx_in_block as i32,
             err: value
                 .err
                 .clone()
@@ -38,6 +63,21 @@ impl PostgresTransaction {
             recent_blockhash: value.recent_blockhash.clone(),
             message: value.message.clone(),
             slot: slot as i64,
+            fee: value.fee as i64,
+            pre_balances: value.pre_balances.iter().map(|x| *x as i64).collect(),
+            post_balances: value.post_balances.iter().map(|x| *x as i64).collect(),
+            inner_instructions: value
+                .inner_instructions
+                .as_ref()
+                .and_then(|x| BASE64.serialize(x).ok()),
+            log_messages: value.log_messages.clone(),
+            loaded_addresses: BASE64
+                .serialize(&value.loaded_addresses)
+                .unwrap_or_default(),
+            return_data: value
+                .return_data
+                .as_ref()
+                .and_then(|x| BASE64.serialize(x).ok()),
         }
     }
 
@@ -47,13 +87,22 @@ impl PostgresTransaction {
             r#"
                 CREATE TABLE IF NOT EXISTS {schema}.transactions (
                     signature VARCHAR(88) NOT NULL,
+                    signatures TEXT[],
                     slot BIGINT NOT NULL,
+                    idx_in_block INT NOT NULL,
                     err TEXT,
                     cu_requested BIGINT,
                     prioritization_fees BIGINT,
                     cu_consumed BIGINT,
                     recent_blockhash TEXT NOT NULL,
                     message TEXT NOT NULL,
+                    fee BIGINT NOT NULL,
+                    pre_balances BIGINT[] NOT NULL,
+                    post_balances BIGINT[] NOT NULL,
+                    inner_instructions TEXT,
+                    log_messages TEXT[],
+                    loaded_addresses TEXT NOT NULL,
+                    return_data TEXT,
                     CONSTRAINT pk_transaction_sig PRIMARY KEY(signature)
                   ) WITH (FILLFACTOR=90);
                   CREATE INDEX idx_slot ON {schema}.transactions USING btree (slot) WITH (FILLFACTOR=90);
@@ -82,30 +131,48 @@ impl PostgresTransaction {
         slot: Slot,
         transactions: &[Self],
     ) -> anyhow::Result<()> {
-        const NB_ARGUMENTS: usize = 8;
+        const NB_ARGUMENTS: usize = 17;
         let tx_count = transactions.len();
         let mut args: Vec<&(dyn ToSql + Sync)> = Vec::with_capacity(NB_ARGUMENTS * tx_count);
 
         for tx in transactions.iter() {
             let PostgresTransaction {
                 signature,
+                signatures,
                 slot,
+                idx_in_block,
                 err,
                 cu_requested,
                 prioritization_fees,
                 cu_consumed,
                 recent_blockhash,
                 message,
+                fee,
+                pre_balances,
+                post_balances,
+                inner_instructions,
+                log_messages,
+                loaded_addresses,
+                return_data,
             } = tx;
 
             args.push(signature);
+            args.push(signatures);
             args.push(slot);
+            args.push(idx_in_block);
             args.push(err);
             args.push(cu_requested);
             args.push(prioritization_fees);
             args.push(cu_consumed);
             args.push(recent_blockhash);
             args.push(message);
+            args.push(fee);
+            args.push(pre_balances);
+            args.push(post_balances);
+            args.push(inner_instructions);
+            args.push(log_messages);
+            args.push(loaded_addresses);
+            args.push(return_data);
         }
 
         let values = PostgresSession::values_vecvec(NB_ARGUMENTS, tx_count, &[]);
@@ -113,7 +180,8 @@ impl PostgresTransaction {
         let statement = format!(
             r#"
                 INSERT INTO {schema}.transactions
-                (signature, slot, err, cu_requested, prioritization_fees, cu_consumed, recent_blockhash, message)
+                (signature, signatures, slot, idx_in_block, err, cu_requested, prioritization_fees, cu_consumed, recent_blockhash, message,
+                 fee, pre_balances, post_balances, inner_instructions, log_messages, loaded_addresses, return_data)
                 VALUES {}
                 ON CONFLICT DO NOTHING
             "#,
@@ -148,7 +216,8 @@ impl PostgresTransaction {
         let statement = format!(
             r#"
                 COPY {schema}.transactions(
-                    signature, slot, err, cu_requested, prioritization_fees, cu_consumed, recent_blockhash, message
+                    signature, signatures, slot, idx_in_block, err, cu_requested, prioritization_fees, cu_consumed, recent_blockhash, message,
+                    fee, pre_balances, post_balances, inner_instructions, log_messages, loaded_addresses, return_data
                 ) FROM STDIN BINARY
             "#,
             schema = schema,
@@ -162,13 +231,22 @@ impl PostgresTransaction {
             sink,
             &[
                 Type::TEXT,
+                Type::TEXT_ARRAY,
                 Type::INT8,
+                Type::INT4,
                 Type::TEXT,
                 Type::INT8,
                 Type::INT8,
                 Type::INT8,
                 Type::TEXT,
                 Type::TEXT,
+                Type::INT8,
+                Type::INT8_ARRAY,
+                Type::INT8_ARRAY,
+                Type::TEXT,
+                Type::TEXT_ARRAY,
+                Type::TEXT,
+                Type::TEXT,
             ],
         );
         pin_mut!(writer);
@@ -176,26 +254,44 @@ impl PostgresTransaction {
         for tx in transactions {
             let PostgresTransaction {
                 signature,
+                signatures,
                 slot,
+                idx_in_block,
                 err,
                 cu_requested,
                 prioritization_fees,
                 cu_consumed,
                 recent_blockhash,
                 message,
+                fee,
+                pre_balances,
+                post_balances,
+                inner_instructions,
+                log_messages,
+                loaded_addresses,
+                return_data,
             } = tx;
 
             writer
                 .as_mut()
                 .write(&[
                     &signature,
+                    &signatures,
                     &slot,
+                    &idx_in_block,
                     &err,
                     &cu_requested,
                     &prioritization_fees,
                     &cu_consumed,
                     &recent_blockhash,
                     &message,
+                    &fee,
+                    &pre_balances,
+                    &post_balances,
+                    &inner_instructions,
+                    &log_messages,
+                    &loaded_addresses,
+                    &return_data,
                 ])
                 .await
                 .unwrap();
@@ -206,21 +302,150 @@ impl PostgresTransaction {
         Ok(true)
     }
 
+    // transactions of one block in the order they appear in the block
     pub async fn get(
-        postgres_session: PostgresSession,
-        schema: &String,
+        postgres_session: &PostgresSession,
+        epoch: EpochRef,
         slot: Slot,
-    ) -> Vec<TransactionInfo> {
+    ) -> anyhow::Result<Vec<TransactionInfo>> {
         let statement = format!(
             r#"
-                SELECT signature, err, cu_requested, prioritization_fees, cu_consumed, recent_blockhash, message
+                SELECT {columns}
                 FROM {schema}.transactions
-                WHERE slot = {}
+                WHERE slot = {slot}
+                ORDER BY idx_in_block
             "#,
-            slot,
-            schema = schema
+            columns = Self::SELECT_COLUMNS,
+            schema = PostgresEpoch::build_schema_name(epoch),
+            slot = slot,
+        );
+
+        let rows = postgres_session.query_list(&statement, &[]).await?;
+
+        rows.iter().map(Self::row_to_transaction_info).collect()
+    }
+
+    /// transaction with the slot and time of its block
+    pub async fn get_by_signature(
+        postgres_session: &PostgresSession,
+        epoch: EpochRef,
+        signature: &str,
+    ) -> anyhow::Result<Option<ConfirmedTransactionInfo>> {
+        let statement = format!(
+            r#"
+                SELECT transactions.slot AS slot, blocks.block_time AS block_time, {columns}
+                FROM {schema}.transactions
+                INNER JOIN {schema}.blocks ON blocks.slot = transactions.slot
+                WHERE signature = $1
+            "#,
+            columns = Self::SELECT_COLUMNS,
+            schema = PostgresEpoch::build_schema_name(epoch),
         );
-        let _ = postgres_session.client.query(&statement, &[]).await;
-        todo!()
+
+        let row = postgres_session
+            .query_opt(&statement, &[&signature])
+            .await?;
+
+        row.as_ref()
+            .map(|row| {
+                Ok(ConfirmedTransactionInfo {
+                    slot: row.get::<&str, i64>("slot") as Slot,
+                    block_time: row.get::<&str, i64>("block_time") as u64,
+                    transaction: Self::row_to_transaction_info(row)?,
+                })
+            })
+            .transpose()
+    }
+
+    fn row_to_transaction_info(row: &Row) -> anyhow::Result<TransactionInfo> {
+        let err: Option<String> = row.get("err");
+        let err = err
+            .map(|err| BASE64.deserialize::<TransactionError>(&err))
+            .transpose()?;
+        let inner_instructions: Option<String> = row.get("inner_instructions");
+        let inner_instructions = inner_instructions
+            .map(|x| BASE64.deserialize::<Vec<InnerInstructions>>(&x))
+            .transpose()?;
+        let loaded_addresses = decode_loaded_addresses(row.get("loaded_addresses"))?;
+        let message: String = row.get("message");
+        // not persisted, derived from the message
+        let writable_accounts = TransactionInfo::get_writable_accounts(
+            &BASE64.deserialize::<VersionedMessage>(&message)?,
+            &loaded_addresses,
+        );
+        let return_data: Option<String> = row.get("return_data");
+        let return_data = return_data
+            .map(|x| BASE64.deserialize::<TransactionReturnData>(&x))
+            .transpose()?;
+
+        let signature: String = row.get("signature");
+        // transactions written before all signatures were stored only have their first one
+        let signatures = row
+            .get::<&str, Option<Vec<String>>>("signatures")
+            .unwrap_or_else(|| vec![signature.clone()]);
+
+        Ok(TransactionInfo {
+            signature,
+            signatures,
+            // not persisted
+            is_vote: false,
+            err,
+            cu_requested: row
+                .get::<&str, Option<i64>>("cu_requested")
+                .map(|x| x as u32),
+            prioritization_fees: row
+                .get::<&str, Option<i64>>("prioritization_fees")
+                .map(|x| x as u64),
+            cu_consumed: row
+                .get::<&str, Option<i64>>("cu_consumed")
+                .map(|x| x as u64),
+            recent_blockhash: row.get("recent_blockhash"),
+            message,
+            loaded_addresses,
+            fee: row.get::<&str, i64>("fee") as u64,
+            pre_balances: row
+                .get::<&str, Vec<i64>>("pre_balances")
+                .into_iter()
+                .map(|x| x as u64)
+                .collect(),
+            post_balances: row
+                .get::<&str, Vec<i64>>("post_balances")
+                .into_iter()
+                .map(|x| x as u64)
+                .collect(),
+            inner_instructions,
+            log_messages: row.get("log_messages"),
+            return_data,
+            writable_accounts,
+        })
+    }
+}
+
+// transactions written before the status meta was stored have no loaded addresses (see migrations)
+fn decode_loaded_addresses(loaded_addresses: String) -> anyhow::Result<LoadedAddresses> {
+    if loaded_addresses.is_empty() {
+        return Ok(LoadedAddresses::default());
+    }
+    BASE64.deserialize::<LoadedAddresses>(&loaded_addresses)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use solana_sdk::pubkey::Pubkey;
+
+    #[test]
+    fn decode_loaded_addresses_of_transactions_without_meta() {
+        assert_eq!(
+            decode_loaded_addresses(String::new()).unwrap(),
+            LoadedAddresses::default()
+        );
+
+        let loaded_addresses = LoadedAddresses {
+            writable: vec![Pubkey::new_unique()],
+            readonly: vec![Pubkey::new_unique()],
+        };
+        let encoded = BASE64.serialize(&loaded_addresses).unwrap();
+        assert_eq!(decode_loaded_addresses(encoded).unwrap(), loaded_addresses);
     }
 }
@@ -1,35 +1,77 @@
-use std::collections::HashMap;
+use std::collections::{BTreeSet, HashMap, HashSet};
+use std::time::{SystemTime, UNIX_EPOCH};
 use std::{str::FromStr, sync::Arc};
 
 use anyhow::Context;
 use jsonrpsee::{
-    core::SubscriptionResult, server::ServerBuilder, DisconnectError, PendingSubscriptionSink,
+    core::{StringError, SubscriptionResult},
+    server::ServerBuilder,
+    types::error::{CallError, ErrorObject},
+    DisconnectError, PendingSubscriptionSink,
 };
 use log::{debug, error, warn};
-use prometheus::{opts, register_int_counter, IntCounter};
+use prometheus::{opts, register_int_counter, register_int_counter_vec, IntCounter, IntCounterVec};
+use solana_account_decoder::parse_account_data::AccountAdditionalData;
+use solana_account_decoder::parse_token::{
+    is_known_spl_token_id, token_amount_to_ui_amount, UiTokenAmount,
+};
+use solana_account_decoder::{UiAccount, UiAccountEncoding, UiDataSliceConfig};
 use solana_rpc_client::nonblocking::rpc_client::RpcClient;
+use solana_rpc_client_api::client_error::{Error as ClientError, ErrorKind as ClientErrorKind};
 use solana_rpc_client_api::{
     config::{
-        RpcBlockSubscribeConfig, RpcBlockSubscribeFilter, RpcBlocksConfigWrapper, RpcContextConfig,
+        RpcAccountInfoConfig, RpcBlockConfig, RpcBlockSubscribeConfig, RpcBlockSubscribeFilter,
+        RpcBlocksConfigWrapper, RpcContextConfig, RpcEncodingConfigWrapper,
         RpcGetVoteAccountsConfig, RpcLeaderScheduleConfig, RpcProgramAccountsConfig,
         RpcRequestAirdropConfig, RpcSignatureStatusConfig, RpcSignatureSubscribeConfig,
-        RpcSignaturesForAddressConfig, RpcTransactionLogsConfig, RpcTransactionLogsFilter,
+        RpcSignaturesForAddressConfig, RpcTokenAccountsFilter, RpcTransactionConfig,
+        RpcTransactionLogsConfig, RpcTransactionLogsFilter,
+    },
+    custom_error::{
+        MinContextSlotNotReachedErrorData, JSON_RPC_SERVER_ERROR_MIN_CONTEXT_SLOT_NOT_REACHED,
+    },
+    request::{
+        RpcError, RpcRequest, RpcResponseErrorData, MAX_GET_CONFIRMED_BLOCKS_RANGE,
+        MAX_GET_CONFIRMED_SIGNATURES_FOR_ADDRESS2_LIMIT, MAX_GET_SIGNATURE_STATUSES_QUERY_ITEMS,
+        MAX_MULTIPLE_ACCOUNTS, NUM_LARGEST_ACCOUNTS,
     },
     response::{
-        Response as RpcResponse, RpcBlockhash, RpcConfirmedTransactionStatusWithSignature,
-        RpcContactInfo, RpcPerfSample, RpcPrioritizationFee, RpcResponseContext, RpcVersionInfo,
-        RpcVoteAccountStatus,
+        OptionalContext, Response as RpcResponse, RpcBlockUpdate, RpcBlockUpdateError,
+        RpcBlockhash, RpcConfirmedTransactionStatusWithSignature, RpcContactInfo, RpcKeyedAccount,
+        RpcLogsResponse, RpcPerfSample, RpcPrioritizationFee, RpcResponseContext,
+        RpcTokenAccountBalance, RpcVersionInfo, RpcVoteAccountStatus,
     },
 };
+use solana_sdk::account::ReadableAccount;
 use solana_sdk::epoch_info::EpochInfo;
-use solana_sdk::{commitment_config::CommitmentConfig, pubkey::Pubkey, slot_history::Slot};
-use solana_transaction_status::{TransactionStatus, UiConfirmedBlock};
+use solana_sdk::transaction::MAX_TX_ACCOUNT_LOCKS;
+use solana_sdk::vote::state::MAX_LOCKOUT_HISTORY;
+use solana_sdk::{
+    commitment_config::CommitmentConfig, pubkey::Pubkey, signature::Signature, slot_history::Slot,
+};
+use solana_transaction_status::{
+    BlockEncodingOptions, EncodeError, TransactionConfirmationStatus, TransactionStatus,
+    UiConfirmedBlock, UiTransactionEncoding,
+};
 use tokio::net::ToSocketAddrs;
 use tokio::sync::broadcast::error::RecvError::{Closed, Lagged};
+use tokio::sync::{broadcast, mpsc, oneshot};
 
 use solana_lite_rpc_core::{
     encoding,
-    stores::{block_information_store::BlockInformation, data_cache::DataCache, tx_store::TxProps},
+    stores::{
+        block_information_store::BlockInformation, cluster_info_store::ClusterVersionCount,
+        data_cache::DataCache, performance_samples_store::MAX_PERFORMANCE_SAMPLES,
+        program_account_store::TokenAccountKey, tx_store::TxProps,
+    },
+    structures::{
+        account_data::{prepare_filters, AccountData, AccountNotificationMessage},
+        leaderschedule::GetVoteAccountsConfig,
+        slot_notification::SlotNotification,
+        token_account::{parse_mint_decimals, TokenAccount},
+        transaction_filter::TransactionFilter,
+    },
+    types::{AccountStream, BlockStream, SlotStream},
     AnyhowJoinHandle,
 };
 use solana_lite_rpc_history::history::History;
@@ -38,8 +80,12 @@ use solana_lite_rpc_services::{
 };
 
 use crate::{
-    configs::{IsBlockHashValidConfig, SendTransactionConfig},
+    configs::{
+        IsBlockHashValidConfig, RpcSignatureStatusesResponse, RpcTransactionSubscribeConfig,
+        RpcTransactionSubscribeFilter, RpcTransactionUpdate, SendTransactionConfig,
+    },
     jsonrpsee_subscrption_handler_sink::JsonRpseeSubscriptionHandlerSink,
+    passthrough::PassthroughConfig,
     rpc::LiteRpcServer,
 };
 use solana_lite_rpc_block_priofees::rpc_data::{PrioFeesStats, PrioFeesUpdateMessage};
@@ -62,8 +108,77 @@ lazy_static::lazy_static! {
     register_int_counter!(opts!("literpc_rpc_signature_subscribe", "RPC call to subscribe to signature")).unwrap();
     static ref RPC_BLOCK_PRIOFEES_SUBSCRIBE: IntCounter =
     register_int_counter!(opts!("literpc_rpc_block_priofees_subscribe", "RPC call to subscribe to block prio fees")).unwrap();
+    static ref RPC_GET_BLOCK: IntCounter =
+    register_int_counter!(opts!("literpc_rpc_get_block", "RPC call to get block")).unwrap();
+    static ref RPC_GET_BLOCKS: IntCounter =
+    register_int_counter!(opts!("literpc_rpc_get_blocks", "RPC call to get blocks")).unwrap();
+    static ref RPC_GET_SIGNATURES_FOR_ADDRESS: IntCounter =
+    register_int_counter!(opts!("literpc_rpc_get_signatures_for_address", "RPC call to get signatures for address")).unwrap();
+    static ref RPC_GET_BLOCKS_WITH_LIMIT: IntCounter =
+    register_int_counter!(opts!("literpc_rpc_get_blocks_with_limit", "RPC call to get blocks with limit")).unwrap();
+    static ref RPC_GET_TRANSACTION: IntCounter =
+    register_int_counter!(opts!("literpc_rpc_get_transaction", "RPC call to get transaction")).unwrap();
+    static ref RPC_GET_BLOCK_HEIGHT: IntCounter =
+    register_int_counter!(opts!("literpc_rpc_get_block_height", "RPC call to get block height")).unwrap();
+    static ref RPC_GET_BLOCK_TIME: IntCounter =
+    register_int_counter!(opts!("literpc_rpc_get_block_time", "RPC call to get block time")).unwrap();
+    static ref RPC_GET_FIRST_AVAILABLE_BLOCK: IntCounter =
+    register_int_counter!(opts!("literpc_rpc_get_first_available_block", "RPC call to get first available block")).unwrap();
+    static ref RPC_GET_RECENT_PERFORMANCE_SAMPLES: IntCounter =
+    register_int_counter!(opts!("literpc_rpc_get_recent_performance_samples", "RPC call to get recent performance samples")).unwrap();
+    static ref RPC_GET_RECENT_PRIORITIZATION_FEES: IntCounter =
+    register_int_counter!(opts!("literpc_rpc_get_recent_prioritization_fees", "RPC call to get recent prioritization fees")).unwrap();
+    static ref RPC_GET_CLUSTER_NODES: IntCounter =
+    register_int_counter!(opts!("literpc_rpc_get_cluster_nodes", "RPC call to get cluster nodes")).unwrap();
+    static ref RPC_GET_CLUSTER_VERSION_SUMMARY: IntCounter =
+    register_int_counter!(opts!("literpc_rpc_get_cluster_version_summary", "RPC call to get cluster version summary")).unwrap();
+    static ref RPC_SLOT_SUBSCRIBE: IntCounter =
+    register_int_counter!(opts!("literpc_rpc_slot_subscribe", "RPC call to subscribe to slots")).unwrap();
+    static ref RPC_SLOT_UPDATES_SUBSCRIBE: IntCounter =
+    register_int_counter!(opts!("literpc_rpc_slot_updates_subscribe", "RPC call to subscribe to slot updates")).unwrap();
+    static ref RPC_BLOCK_SUBSCRIBE: IntCounter =
+    register_int_counter!(opts!("literpc_rpc_block_subscribe", "RPC call to subscribe to blocks")).unwrap();
+    static ref RPC_LOGS_SUBSCRIBE: IntCounter =
+    register_int_counter!(opts!("literpc_rpc_logs_subscribe", "RPC call to subscribe to transaction logs")).unwrap();
+    static ref RPC_VOTE_SUBSCRIBE: IntCounter =
+    register_int_counter!(opts!("literpc_rpc_vote_subscribe", "RPC call to subscribe to votes")).unwrap();
+    static ref RPC_GET_ACCOUNT_INFO: IntCounter =
+    register_int_counter!(opts!("literpc_rpc_get_account_info", "RPC call to get account info")).unwrap();
+    static ref RPC_GET_MULTIPLE_ACCOUNTS: IntCounter =
+    register_int_counter!(opts!("literpc_rpc_get_multiple_accounts", "RPC call to get multiple accounts")).unwrap();
+    static ref RPC_GET_BALANCE: IntCounter =
+    register_int_counter!(opts!("literpc_rpc_get_balance", "RPC call to get balance")).unwrap();
+    static ref ACCOUNT_CACHE_HITS: IntCounter =
+    register_int_counter!(opts!("literpc_account_cache_hits", "Accounts served from the account cache")).unwrap();
+    static ref ACCOUNT_CACHE_MISSES: IntCounter =
+    register_int_counter!(opts!("literpc_account_cache_misses", "Accounts fetched from rpc on account cache miss")).unwrap();
+    static ref RPC_GET_PROGRAM_ACCOUNTS: IntCounter =
+    register_int_counter!(opts!("literpc_rpc_get_program_accounts", "RPC call to get program accounts")).unwrap();
+    static ref RPC_GET_TOKEN_ACCOUNTS_BY_OWNER: IntCounter =
+    register_int_counter!(opts!("literpc_rpc_get_token_accounts_by_owner", "RPC call to get token accounts by owner")).unwrap();
+    static ref RPC_GET_TOKEN_ACCOUNTS_BY_DELEGATE: IntCounter =
+    register_int_counter!(opts!("literpc_rpc_get_token_accounts_by_delegate", "RPC call to get token accounts by delegate")).unwrap();
+    static ref RPC_GET_TOKEN_ACCOUNT_BALANCE: IntCounter =
+    register_int_counter!(opts!("literpc_rpc_get_token_account_balance", "RPC call to get token account balance")).unwrap();
+    static ref RPC_GET_TOKEN_LARGEST_ACCOUNTS: IntCounter =
+    register_int_counter!(opts!("literpc_rpc_get_token_largest_accounts", "RPC call to get largest token accounts of a mint")).unwrap();
+    static ref RPC_PROGRAM_SUBSCRIBE: IntCounter =
+    register_int_counter!(opts!("literpc_rpc_program_subscribe", "RPC call to subscribe to program accounts")).unwrap();
+    static ref RPC_ACCOUNT_SUBSCRIBE: IntCounter =
+    register_int_counter!(opts!("literpc_rpc_account_subscribe", "RPC call to subscribe to an account")).unwrap();
+    static ref RPC_TRANSACTION_SUBSCRIBE: IntCounter =
+    register_int_counter!(opts!("literpc_rpc_transaction_subscribe", "RPC call to subscribe to transactions")).unwrap();
+    static ref RPC_GET_VOTE_ACCOUNTS: IntCounter =
+    register_int_counter!(opts!("literpc_rpc_get_vote_accounts", "RPC call to get vote accounts")).unwrap();
+    static ref RPC_PASSTHROUGH: IntCounterVec =
+    register_int_counter_vec!(opts!("literpc_rpc_passthrough", "RPC call forwarded to the upstream rpc"), &["method"]).unwrap();
+    static ref RPC_SUBSCRIPTIONS_CLOSED_LAGGED: IntCounter =
+    register_int_counter!(opts!("literpc_rpc_subscriptions_closed_lagged", "Subscriptions closed because the subscriber lagged behind")).unwrap();
 }
 
+// enough to not publish the votes of a slot again when the block gets confirmed
+const VOTE_SUBSCRIBE_SLOTS_TO_RETAIN: usize = 512;
+
 /// A bridge between clients and tpu
 #[allow(dead_code)]
 pub struct LiteBridge {
@@ -73,15 +188,30 @@ pub struct LiteBridge {
     transaction_service: TransactionService,
     history: History,
     prio_fees_service: PrioFeesService,
+    slot_notifier: SlotStream,
+    blocks_notifier: BlockStream,
+    account_notifier: Option<AccountStream>,
+    // requests to the stake and vote engine, None when stakes are not computed from geyser
+    vote_accounts_sender:
+        Option<mpsc::Sender<(GetVoteAccountsConfig, oneshot::Sender<RpcVoteAccountStatus>)>>,
+    passthrough: PassthroughConfig,
 }
 
 impl LiteBridge {
+    #[allow(clippy::too_many_arguments)]
     pub fn new(
         rpc_client: Arc<RpcClient>,
         data_cache: DataCache,
         transaction_service: TransactionService,
         history: History,
         prio_fees_service: PrioFeesService,
+        slot_notifier: SlotStream,
+        blocks_notifier: BlockStream,
+        account_notifier: Option<AccountStream>,
+        vote_accounts_sender: Option<
+            mpsc::Sender<(GetVoteAccountsConfig, oneshot::Sender<RpcVoteAccountStatus>)>,
+        >,
+        passthrough: PassthroughConfig,
     ) -> Self {
         Self {
             rpc_client,
@@ -89,7 +219,297 @@ impl LiteBridge {
             transaction_service,
             history,
             prio_fees_service,
+            slot_notifier,
+            blocks_notifier,
+            account_notifier,
+            vote_accounts_sender,
+            passthrough,
+        }
+    }
+
+    /// highest slot a block can be listed for at the given commitment
+    async fn get_highest_block_slot(
+        &self,
+        commitment_config: CommitmentConfig,
+    ) -> crate::rpc::Result<Slot> {
+        if commitment_config.is_processed() {
+            return Err(jsonrpsee::core::Error::Custom(
+                "Method does not support commitment below `confirmed`".to_string(),
+            ));
+        }
+
+        Ok(self
+            .data_cache
+            .block_information_store
+            .get_latest_block_info(commitment_config)
+            .await
+            .slot)
+    }
+
+    /// slot of the latest block at the given commitment
+    async fn get_context_slot(
+        &self,
+        commitment_config: CommitmentConfig,
+        min_context_slot: Option<Slot>,
+    ) -> crate::rpc::Result<Slot> {
+        let context_slot = self
+            .data_cache
+            .block_information_store
+            .get_latest_block(commitment_config)
+            .await
+            .slot;
+        if let Some(min_context_slot) = min_context_slot {
+            if context_slot < min_context_slot {
+                // same error as solana rpc so that clients and load balancers can retry elsewhere
+                return Err(jsonrpsee::core::Error::Call(CallError::Custom(
+                    ErrorObject::owned(
+                        JSON_RPC_SERVER_ERROR_MIN_CONTEXT_SLOT_NOT_REACHED as i32,
+                        "Minimum context slot has not been reached",
+                        Some(MinContextSlotNotReachedErrorData { context_slot }),
+                    ),
+                )));
+            }
+        }
+        Ok(context_slot)
+    }
+
+    /// accounts from the program account index or the account cache, the misses are fetched from rpc and streamed from then on
+    /// returns the context slot and the accounts in the order of the pubkeys
+    async fn get_accounts(
+        &self,
+        pubkeys: &[Pubkey],
+        commitment_config: CommitmentConfig,
+        min_context_slot: Option<Slot>,
+    ) -> crate::rpc::Result<(Slot, Vec<Option<AccountData>>)> {
+        let mut context_slot = self
+            .get_context_slot(commitment_config, min_context_slot)
+            .await?;
+
+        let mut accounts = pubkeys
+            .iter()
+            .map(|pubkey| {
+                self.data_cache
+                    .program_accounts
+                    .get_account(pubkey, commitment_config)
+                    .or_else(|| {
+                        self.data_cache
+                            .accounts
+                            .get_account(pubkey, commitment_config)
+                    })
+            })
+            .collect::<Vec<_>>();
+        let misses = pubkeys
+            .iter()
+            .zip(&accounts)
+            .filter(|(_, account)| account.is_none())
+            .map(|(pubkey, _)| *pubkey)
+            .collect::<Vec<_>>();
+        ACCOUNT_CACHE_HITS.inc_by((pubkeys.len() - misses.len()) as u64);
+        ACCOUNT_CACHE_MISSES.inc_by(misses.len() as u64);
+
+        if !misses.is_empty() {
+            // without account stream the cached account would never be updated
+            let cache_fetched = self.account_notifier.is_some();
+            // streamed before the fetch, the updates racing with it win over the fetched copy
+            if cache_fetched {
+                for pubkey in &misses {
+                    self.data_cache.accounts.subscribe_on_demand(*pubkey);
+                }
+            }
+            let response = self
+                .rpc_client
+                .get_multiple_accounts_with_config(
+                    &misses,
+                    RpcAccountInfoConfig {
+                        commitment: Some(commitment_config),
+                        min_context_slot,
+                        ..Default::default()
+                    },
+                )
+                .await
+                .map_err(|err| jsonrpsee::core::Error::Custom(err.to_string()))?;
+            context_slot = context_slot.max(response.context.slot);
+
+            let mut fetched = misses.iter().zip(response.value).map(|(pubkey, account)| {
+                account.map(|account| AccountData {
+                    pubkey: *pubkey,
+                    account: account.into(),
+                    updated_slot: response.context.slot,
+                    write_version: 0,
+                })
+            });
+            for account in accounts.iter_mut().filter(|account| account.is_none()) {
+                *account = fetched.next().flatten();
+                match account {
+                    Some(data) if cache_fetched => {
+                        let accounts = &self.data_cache.accounts;
+                        accounts.add_account_on_demand(data.clone(), commitment_config);
+                        if let Some(streamed) =
+                            accounts.get_account(&data.pubkey, commitment_config)
+                        {
+                            *data = streamed;
+                        }
+                    }
+                    _ => {}
+                }
+            }
+        }
+
+        let newest_account_slot = accounts
+            .iter()
+            .flatten()
+            .map(|data| data.updated_slot)
+            .max();
+        Ok((
+            context_slot.max(newest_account_slot.unwrap_or_default()),
+            accounts,
+        ))
+    }
+
+    /// request served by the upstream rpc
+    async fn forward<T: serde::de::DeserializeOwned>(
+        &self,
+        request: RpcRequest,
+        params: serde_json::Value,
+    ) -> crate::rpc::Result<T> {
+        self.rpc_client
+            .send(request, params)
+            .await
+            .map_err(upstream_error)
+    }
+
+    /// decimals of the mints; unknown mints are left out
+    async fn get_mint_decimals(
+        &self,
+        mints: &[Pubkey],
+        commitment_config: CommitmentConfig,
+    ) -> crate::rpc::Result<HashMap<Pubkey, u8>> {
+        let mut mint_decimals = HashMap::new();
+        for mints in mints.chunks(MAX_MULTIPLE_ACCOUNTS) {
+            let (_, accounts) = self.get_accounts(mints, commitment_config, None).await?;
+            mint_decimals.extend(
+                mints
+                    .iter()
+                    .zip(accounts)
+                    .filter_map(|(mint, data)| Some((*mint, parse_mint_decimals(&data?)?))),
+            );
+        }
+        Ok(mint_decimals)
+    }
+
+    /// decimals of the mints of the token accounts, jsonParsed token amounts need them
+    async fn get_token_mint_decimals<'a>(
+        &self,
+        accounts: impl IntoIterator<Item = &'a AccountData>,
+        commitment_config: CommitmentConfig,
+        encoding: UiAccountEncoding,
+    ) -> crate::rpc::Result<HashMap<Pubkey, u8>> {
+        if encoding != UiAccountEncoding::JsonParsed {
+            return Ok(HashMap::new());
         }
+        let mints = accounts
+            .into_iter()
+            .filter_map(TokenAccount::parse)
+            .map(|token_account| token_account.mint)
+            .collect::<BTreeSet<_>>()
+            .into_iter()
+            .collect::<Vec<_>>();
+        self.get_mint_decimals(&mints, commitment_config).await
+    }
+
+    /// a notification is still sent if the mint is unavailable, the token account is encoded as base64 then
+    async fn get_subscription_mint_decimals(
+        &self,
+        data: &AccountData,
+        commitment_config: CommitmentConfig,
+        encoding: UiAccountEncoding,
+    ) -> HashMap<Pubkey, u8> {
+        self.get_token_mint_decimals([data], commitment_config, encoding)
+            .await
+            .unwrap_or_else(|err| {
+                warn!(
+                    "Cannot get the mint of token account {}: {err}",
+                    data.pubkey
+                );
+                HashMap::new()
+            })
+    }
+
+    /// token accounts by owner or delegate from the token index, forwarded to rpc if tokens are not indexed
+    async fn get_token_accounts(
+        &self,
+        request: RpcRequest,
+        key: TokenAccountKey,
+        filter: RpcTokenAccountsFilter,
+        config: Option<RpcAccountInfoConfig>,
+    ) -> crate::rpc::Result<RpcResponse<Vec<RpcKeyedAccount>>> {
+        let parse_pubkey = |pubkey_str: &str| {
+            Pubkey::from_str(pubkey_str)
+                .map_err(|err| jsonrpsee::core::Error::Custom(format!("Invalid param: {err}")))
+        };
+        let (mint, program_id) = match &filter {
+            RpcTokenAccountsFilter::Mint(mint) => (Some(parse_pubkey(mint)?), None),
+            RpcTokenAccountsFilter::ProgramId(program_id) => {
+                let program_id = parse_pubkey(program_id)?;
+                if !is_known_spl_token_id(&program_id) {
+                    return Err(jsonrpsee::core::Error::Custom(
+                        "Invalid param: unrecognized Token program id".to_string(),
+                    ));
+                }
+                (None, Some(program_id))
+            }
+        };
+
+        let account_config = config.clone().unwrap_or_default();
+        let commitment_config = account_config.commitment.unwrap_or_default();
+        let Some(token_accounts) = self
+            .data_cache
+            .program_accounts
+            .get_token_accounts(&key, commitment_config)
+        else {
+            let (TokenAccountKey::Owner(pubkey)
+            | TokenAccountKey::Delegate(pubkey)
+            | TokenAccountKey::Mint(pubkey)) = key;
+            return self
+                .forward(
+                    request,
+                    serde_json::json!([pubkey.to_string(), filter, config]),
+                )
+                .await;
+        };
+
+        let slot = self
+            .get_context_slot(commitment_config, account_config.min_context_slot)
+            .await?;
+        let token_accounts = token_accounts
+            .into_iter()
+            .filter(|(data, token_account)| {
+                mint.map_or(true, |mint| token_account.mint == mint)
+                    && program_id.map_or(true, |program_id| data.account.owner() == &program_id)
+            })
+            .map(|(data, _)| data)
+            .collect::<Vec<_>>();
+        // same default as solana rpc
+        let encoding = account_config.encoding.unwrap_or(UiAccountEncoding::Binary);
+        let mint_decimals = self
+            .get_token_mint_decimals(&token_accounts, commitment_config, encoding)
+            .await?;
+
+        Ok(RpcResponse {
+            context: RpcResponseContext::new(slot),
+            value: token_accounts
+                .iter()
+                .map(|data| RpcKeyedAccount {
+                    pubkey: data.pubkey.to_string(),
+                    account: encode_account(
+                        data,
+                        encoding,
+                        account_config.data_slice,
+                        &mint_decimals,
+                    ),
+                })
+                .collect(),
+        })
     }
 
     /// List for `JsonRpc` requests
@@ -98,7 +518,26 @@ impl LiteBridge {
         http_addr: T,
         ws_addr: T,
     ) -> anyhow::Result<()> {
-        let rpc = self.into_rpc();
+        let passthrough = self.passthrough.clone();
+        let mut rpc = self.into_rpc();
+
+        let implemented_methods = rpc.method_names().collect::<HashSet<_>>();
+        let forwarded_methods = passthrough.forwarded_methods(&implemented_methods);
+        for method in &forwarded_methods {
+            let method = *method;
+            // listed with 0 calls so the methods lacking a native implementation are visible
+            RPC_PASSTHROUGH.with_label_values(&[method]).reset();
+            rpc.register_async_method(method, move |params, bridge| async move {
+                RPC_PASSTHROUGH.with_label_values(&[method]).inc();
+                let params = params.parse::<serde_json::Value>()?;
+                bridge
+                    .forward::<serde_json::Value>(RpcRequest::Custom { method }, params)
+                    .await
+            })?;
+        }
+        if !forwarded_methods.is_empty() {
+            log::info!("Forwarding {forwarded_methods:?} to the upstream rpc");
+        }
 
         let ws_server_handle = ServerBuilder::default()
             .ws_only()
@@ -135,65 +574,348 @@ impl LiteBridge {
     }
 }
 
+/// jsonParsed token accounts are encoded with the decimals of their mint, other accounts as solana rpc does
+fn encode_account(
+    data: &AccountData,
+    encoding: UiAccountEncoding,
+    data_slice: Option<UiDataSliceConfig>,
+    mint_decimals: &HashMap<Pubkey, u8>,
+) -> UiAccount {
+    let additional_data = TokenAccount::parse(data)
+        .and_then(|token_account| mint_decimals.get(&token_account.mint))
+        .map(|decimals| AccountAdditionalData {
+            spl_token_decimals: Some(*decimals),
+        });
+    UiAccount::encode(
+        &data.pubkey,
+        &data.account,
+        encoding,
+        additional_data,
+        data_slice,
+    )
+}
+
+/// keeps the error code and data of the upstream rpc
+fn upstream_error(err: ClientError) -> jsonrpsee::core::Error {
+    let ClientErrorKind::RpcError(RpcError::RpcResponseError {
+        code,
+        message,
+        data,
+    }) = err.kind()
+    else {
+        return jsonrpsee::core::Error::Custom(err.to_string());
+    };
+    let data = match data {
+        RpcResponseErrorData::Empty => None,
+        RpcResponseErrorData::SendTransactionPreflightFailure(result) => {
+            serde_json::to_value(result).ok()
+        }
+        RpcResponseErrorData::NodeUnhealthy { num_slots_behind } => {
+            Some(serde_json::json!({ "numSlotsBehind": num_slots_behind }))
+        }
+    };
+    jsonrpsee::core::Error::Call(CallError::Custom(ErrorObject::owned(
+        *code as i32,
+        message.clone(),
+        data,
+    )))
+}
+
+/// next message for a subscription task, None once the sender is gone
+/// a subscriber which lagged behind missed notifications and cannot trust the stream anymore; the error closes
+/// the subscription with an error notification so the client knows it has to resubscribe and catch up
+async fn recv_subscription_message<T: Clone>(
+    receiver: &mut broadcast::Receiver<T>,
+    subscription: &str,
+) -> Result<Option<T>, StringError> {
+    match receiver.recv().await {
+        Ok(message) => Ok(Some(message)),
+        Err(Lagged(lagged)) => {
+            warn!("{subscription} subscriber lagged {lagged} messages - closing subscription");
+            RPC_SUBSCRIPTIONS_CLOSED_LAGGED.inc();
+            Err(format!(
+                "{subscription} subscription lagged {lagged} notifications behind and was closed, resubscribe to continue"
+            )
+            .into())
+        }
+        Err(Closed) => {
+            error!("failed to receive {subscription} message, sender closed - aborting");
+            Ok(None)
+        }
+    }
+}
+
 #[jsonrpsee::core::async_trait]
 impl LiteRpcServer for LiteBridge {
-    async fn get_block(&self, _slot: u64) -> crate::rpc::Result<Option<UiConfirmedBlock>> {
-        // let block = self.history.block_storage.query_block(slot).await;
-        // if block.is_ok() {
-        //     // TO DO Convert to UIConfirmed Block
-        //     Err(jsonrpsee::core::Error::HttpNotImplemented)
-        // } else {
-        //     Ok(None)
-        // }
+    async fn get_block(
+        &self,
+        slot: u64,
+        config: Option<RpcEncodingConfigWrapper<RpcBlockConfig>>,
+    ) -> crate::rpc::Result<Option<UiConfirmedBlock>> {
+        RPC_GET_BLOCK.inc();
+
+        let config = config
+            .map(|config| config.convert_to_current())
+            .unwrap_or_default();
 
-        // TODO get_block might deserve different implementation based on whether we serve from "history module" vs. from "send tx module"
-        todo!("get_block: decide where to look")
+        let commitment_config = config.commitment.unwrap_or_default();
+        if commitment_config.is_processed() {
+            return Err(jsonrpsee::core::Error::Custom(
+                "Method does not support commitment below `confirmed`".to_string(),
+            ));
+        }
+
+        let Some(block) = self.history.get_block(slot).await else {
+            return Ok(None);
+        };
+
+        // blocks from persistent storage do not carry their commitment level
+        if commitment_config.is_finalized() {
+            let finalized_slot = self
+                .data_cache
+                .block_information_store
+                .get_latest_block_info(CommitmentConfig::finalized())
+                .await
+                .slot;
+            if block.slot > finalized_slot {
+                return Ok(None);
+            }
+        }
+
+        let encoding = config.encoding.unwrap_or(UiTransactionEncoding::Json);
+        let options = BlockEncodingOptions {
+            transaction_details: config.transaction_details.unwrap_or_default(),
+            show_rewards: config.rewards.unwrap_or(true),
+            max_supported_transaction_version: config.max_supported_transaction_version,
+        };
+
+        block
+            .encode_with_options(encoding, options)
+            .map(Some)
+            .map_err(|err| jsonrpsee::core::Error::Custom(err.to_string()))
     }
 
     async fn get_blocks(
         &self,
-        _start_slot: Slot,
-        _config: Option<RpcBlocksConfigWrapper>,
-        _commitment: Option<CommitmentConfig>,
+        start_slot: Slot,
+        config: Option<RpcBlocksConfigWrapper>,
+        commitment: Option<CommitmentConfig>,
+    ) -> crate::rpc::Result<Vec<Slot>> {
+        RPC_GET_BLOCKS.inc();
+
+        let (end_slot, maybe_commitment) = config.map(|config| config.unzip()).unwrap_or_default();
+        let commitment_config = commitment.or(maybe_commitment).unwrap_or_default();
+
+        let highest_slot = self.get_highest_block_slot(commitment_config).await?;
+        let end_slot = end_slot.unwrap_or(highest_slot).min(highest_slot);
+
+        if end_slot.saturating_sub(start_slot) > MAX_GET_CONFIRMED_BLOCKS_RANGE {
+            return Err(jsonrpsee::core::Error::Custom(format!(
+                "Slot range too large; max {MAX_GET_CONFIRMED_BLOCKS_RANGE}"
+            )));
+        }
+
+        self.history
+            .get_block_slots(start_slot..=end_slot, None)
+            .await
+            .map_err(|err| jsonrpsee::core::Error::Custom(err.to_string()))
+    }
+
+    async fn get_blocks_with_limit(
+        &self,
+        start_slot: Slot,
+        limit: usize,
+        commitment: Option<CommitmentConfig>,
     ) -> crate::rpc::Result<Vec<Slot>> {
-        todo!()
+        RPC_GET_BLOCKS_WITH_LIMIT.inc();
+
+        if limit > MAX_GET_CONFIRMED_BLOCKS_RANGE as usize {
+            return Err(jsonrpsee::core::Error::Custom(format!(
+                "Limit too large; max {MAX_GET_CONFIRMED_BLOCKS_RANGE}"
+            )));
+        }
+        if limit == 0 {
+            return Ok(vec![]);
+        }
+
+        let highest_slot = self
+            .get_highest_block_slot(commitment.unwrap_or_default())
+            .await?;
+
+        self.history
+            .get_block_slots(start_slot..=highest_slot, Some(limit))
+            .await
+            .map_err(|err| jsonrpsee::core::Error::Custom(err.to_string()))
     }
 
     async fn get_signatures_for_address(
         &self,
-        _address: String,
-        _config: Option<RpcSignaturesForAddressConfig>,
+        address: String,
+        config: Option<RpcSignaturesForAddressConfig>,
     ) -> crate::rpc::Result<Vec<RpcConfirmedTransactionStatusWithSignature>> {
-        todo!()
+        RPC_GET_SIGNATURES_FOR_ADDRESS.inc();
+
+        let RpcSignaturesForAddressConfig {
+            before,
+            until,
+            limit,
+            commitment,
+            min_context_slot,
+        } = config.unwrap_or_default();
+
+        let account_key = Pubkey::from_str(&address)
+            .map_err(|err| jsonrpsee::core::Error::Custom(format!("Invalid param: {err}")))?;
+        let before = before
+            .map(|before| Signature::from_str(&before))
+            .transpose()
+            .map_err(|err| jsonrpsee::core::Error::Custom(format!("Invalid param: {err}")))?;
+        let until = until
+            .map(|until| Signature::from_str(&until))
+            .transpose()
+            .map_err(|err| jsonrpsee::core::Error::Custom(format!("Invalid param: {err}")))?;
+
+        let limit = limit.unwrap_or(MAX_GET_CONFIRMED_SIGNATURES_FOR_ADDRESS2_LIMIT);
+        if limit == 0 || limit > MAX_GET_CONFIRMED_SIGNATURES_FOR_ADDRESS2_LIMIT {
+            return Err(jsonrpsee::core::Error::Custom(format!(
+                "Invalid limit; max {MAX_GET_CONFIRMED_SIGNATURES_FOR_ADDRESS2_LIMIT}"
+            )));
+        }
+
+        self.get_context_slot(commitment.unwrap_or_default(), min_context_slot)
+            .await?;
+        let highest_slot = self
+            .get_highest_block_slot(commitment.unwrap_or_default())
+            .await?;
+        let finalized_slot = self
+            .data_cache
+            .block_information_store
+            .get_latest_block_info(CommitmentConfig::finalized())
+            .await
+            .slot;
+
+        let signatures = self
+            .history
+            .get_signatures_for_address(
+                &account_key,
+                before.as_ref(),
+                until.as_ref(),
+                highest_slot,
+                limit,
+            )
+            .await
+            .map_err(|err| jsonrpsee::core::Error::Custom(err.to_string()))?;
+
+        Ok(signatures
+            .into_iter()
+            .map(|signature| RpcConfirmedTransactionStatusWithSignature {
+                confirmation_status: Some(if signature.slot <= finalized_slot {
+                    TransactionConfirmationStatus::Finalized
+                } else {
+                    TransactionConfirmationStatus::Confirmed
+                }),
+                ..signature
+            })
+            .collect())
+    }
+
+    async fn get_transaction(
+        &self,
+        signature_str: String,
+        config: Option<RpcEncodingConfigWrapper<RpcTransactionConfig>>,
+    ) -> crate::rpc::Result<Option<serde_json::Value>> {
+        RPC_GET_TRANSACTION.inc();
+
+        let signature = Signature::from_str(&signature_str)
+            .map_err(|err| jsonrpsee::core::Error::Custom(format!("Invalid param: {err}")))?;
+
+        let config = config
+            .map(|config| config.convert_to_current())
+            .unwrap_or_default();
+
+        let highest_slot = self
+            .get_highest_block_slot(config.commitment.unwrap_or_default())
+            .await?;
+
+        let Some(transaction) = self
+            .history
+            .get_transaction(&signature)
+            .await
+            .map_err(|err| jsonrpsee::core::Error::Custom(err.to_string()))?
+        else {
+            return Ok(None);
+        };
+
+        if transaction.slot > highest_slot {
+            return Ok(None);
+        }
+
+        let encoded = transaction
+            .encode(
+                config.encoding.unwrap_or(UiTransactionEncoding::Json),
+                config.max_supported_transaction_version,
+            )
+            .map_err(|err| jsonrpsee::core::Error::Custom(err.to_string()))?;
+
+        serde_json::to_value(encoded)
+            .map(Some)
+            .map_err(|err| jsonrpsee::core::Error::Custom(err.to_string()))
     }
 
     async fn get_cluster_nodes(&self) -> crate::rpc::Result<Vec<RpcContactInfo>> {
-        todo!()
+        RPC_GET_CLUSTER_NODES.inc();
+
+        Ok(self.data_cache.cluster_info.get_cluster_nodes())
     }
 
     async fn get_slot(&self, config: Option<RpcContextConfig>) -> crate::rpc::Result<Slot> {
-        let commitment_config = config
-            .map(|config| config.commitment.unwrap_or_default())
-            .unwrap_or_default();
+        let RpcContextConfig {
+            commitment,
+            min_context_slot,
+        } = config.unwrap_or_default();
+
+        self.get_context_slot(commitment.unwrap_or_default(), min_context_slot)
+            .await
+    }
+
+    async fn get_block_height(&self, config: Option<RpcContextConfig>) -> crate::rpc::Result<u64> {
+        RPC_GET_BLOCK_HEIGHT.inc();
+
+        let RpcContextConfig {
+            commitment,
+            min_context_slot,
+        } = config.unwrap_or_default();
+        let commitment_config = commitment.unwrap_or_default();
+        self.get_context_slot(commitment_config, min_context_slot)
+            .await?;
 
-        let BlockInformation { slot, .. } = self
+        let BlockInformation { block_height, .. } = self
             .data_cache
             .block_information_store
             .get_latest_block(commitment_config)
             .await;
-        Ok(slot)
+        Ok(block_height)
     }
 
-    async fn get_block_height(&self, _config: Option<RpcContextConfig>) -> crate::rpc::Result<u64> {
-        todo!()
-    }
+    async fn get_block_time(&self, block: u64) -> crate::rpc::Result<u64> {
+        RPC_GET_BLOCK_TIME.inc();
 
-    async fn get_block_time(&self, _block: u64) -> crate::rpc::Result<u64> {
-        todo!()
+        match self.history.get_block_time(block).await {
+            Some(block_time) => Ok(block_time),
+            None => Err(jsonrpsee::core::Error::Custom(format!(
+                "Block not available for slot {block}"
+            ))),
+        }
     }
 
     async fn get_first_available_block(&self) -> crate::rpc::Result<u64> {
-        todo!()
+        RPC_GET_FIRST_AVAILABLE_BLOCK.inc();
+
+        match self.history.get_first_available_block().await {
+            Some(slot) => Ok(slot),
+            None => Err(jsonrpsee::core::Error::Custom(
+                "No block available yet".to_string(),
+            )),
+        }
     }
 
     async fn get_latest_blockhash(
@@ -202,9 +924,13 @@ impl LiteRpcServer for LiteBridge {
     ) -> crate::rpc::Result<RpcResponse<RpcBlockhash>> {
         RPC_GET_LATEST_BLOCKHASH.inc();
 
-        let commitment_config = config
-            .map(|config| config.commitment.unwrap_or_default())
-            .unwrap_or_default();
+        let RpcContextConfig {
+            commitment,
+            min_context_slot,
+        } = config.unwrap_or_default();
+        let commitment_config = commitment.unwrap_or_default();
+        self.get_context_slot(commitment_config, min_context_slot)
+            .await?;
 
         let BlockInformation {
             slot,
@@ -220,10 +946,7 @@ impl LiteRpcServer for LiteBridge {
         log::trace!("glb {blockhash} {slot} {block_height}");
 
         Ok(RpcResponse {
-            context: RpcResponseContext {
-                slot,
-                api_version: None,
-            },
+            context: RpcResponseContext::new(slot),
             value: RpcBlockhash {
                 blockhash,
                 last_valid_block_height: block_height + 150,
@@ -238,8 +961,14 @@ impl LiteRpcServer for LiteBridge {
     ) -> crate::rpc::Result<RpcResponse<bool>> {
         RPC_IS_BLOCKHASH_VALID.inc();
 
-        let commitment = config.unwrap_or_default().commitment.unwrap_or_default();
-        let commitment = CommitmentConfig { commitment };
+        let IsBlockHashValidConfig {
+            commitment,
+            min_context_slot,
+        } = config.unwrap_or_default();
+        let commitment = CommitmentConfig {
+            commitment: commitment.unwrap_or_default(),
+        };
+        self.get_context_slot(commitment, min_context_slot).await?;
 
         let (is_valid, slot) = self
             .data_cache
@@ -248,10 +977,7 @@ impl LiteRpcServer for LiteBridge {
             .await;
 
         Ok(RpcResponse {
-            context: RpcResponseContext {
-                slot,
-                api_version: None,
-            },
+            context: RpcResponseContext::new(slot),
             value: is_valid,
         })
     }
@@ -260,9 +986,13 @@ impl LiteRpcServer for LiteBridge {
         &self,
         config: Option<RpcContextConfig>,
     ) -> crate::rpc::Result<EpochInfo> {
-        let commitment_config = config
-            .map(|config| config.commitment.unwrap_or_default())
-            .unwrap_or_default();
+        let RpcContextConfig {
+            commitment,
+            min_context_slot,
+        } = config.unwrap_or_default();
+        let commitment_config = commitment.unwrap_or_default();
+        self.get_context_slot(commitment_config, min_context_slot)
+            .await?;
         let block_info = self
             .data_cache
             .block_information_store
@@ -280,42 +1010,144 @@ impl LiteRpcServer for LiteBridge {
 
     async fn get_recent_performance_samples(
         &self,
-        _limit: Option<usize>,
+        limit: Option<usize>,
     ) -> crate::rpc::Result<Vec<RpcPerfSample>> {
-        todo!()
+        RPC_GET_RECENT_PERFORMANCE_SAMPLES.inc();
+
+        let limit = limit.unwrap_or(MAX_PERFORMANCE_SAMPLES);
+        if limit > MAX_PERFORMANCE_SAMPLES {
+            return Err(jsonrpsee::core::Error::Custom(format!(
+                "Invalid limit; max {MAX_PERFORMANCE_SAMPLES}"
+            )));
+        }
+
+        Ok(self
+            .data_cache
+            .performance_samples
+            .get_recent_samples(limit))
     }
 
     async fn get_signature_statuses(
         &self,
         sigs: Vec<String>,
-        _config: Option<RpcSignatureStatusConfig>,
-    ) -> crate::rpc::Result<RpcResponse<Vec<Option<TransactionStatus>>>> {
+        config: Option<RpcSignatureStatusConfig>,
+    ) -> crate::rpc::Result<RpcSignatureStatusesResponse> {
         RPC_GET_SIGNATURE_STATUSES.inc();
 
-        let sig_statuses = sigs
+        if sigs.len() > MAX_GET_SIGNATURE_STATUSES_QUERY_ITEMS {
+            return Err(jsonrpsee::core::Error::Custom(format!(
+                "Too many inputs provided; max {MAX_GET_SIGNATURE_STATUSES_QUERY_ITEMS}"
+            )));
+        }
+        let signatures = sigs
             .iter()
-            .map(|sig| self.data_cache.txs.get(sig).and_then(|v| v.status))
-            .collect();
+            .map(|sig| Signature::from_str(sig))
+            .collect::<Result<Vec<_>, _>>()
+            .map_err(|err| jsonrpsee::core::Error::Custom(format!("Invalid param: {err}")))?;
+        let search_transaction_history = config
+            .map(|config| config.search_transaction_history)
+            .unwrap_or_default();
 
-        Ok(RpcResponse {
-            context: RpcResponseContext {
-                slot: self
-                    .data_cache
-                    .block_information_store
-                    .get_latest_block_info(CommitmentConfig::finalized())
-                    .await
-                    .slot,
-                api_version: None,
-            },
+        let finalized_block = self
+            .data_cache
+            .block_information_store
+            .get_latest_block_info(CommitmentConfig::finalized())
+            .await;
+        // solana counts the confirmed blocks built on top until the block is rooted;
+        // approximated with the distance to the root, which trails the tip by the lockout history
+        let root = finalized_block.slot;
+        let confirmations = |slot: Slot| {
+            (slot > root).then(|| MAX_LOCKOUT_HISTORY.saturating_sub((slot - root) as usize))
+        };
+
+        let mut expired_signatures = vec![];
+        let mut sig_statuses = sigs
+            .iter()
+            .map(|sig| {
+                let props = self.data_cache.txs.get(sig)?;
+                if props.is_expired(finalized_block.block_height) {
+                    // can no longer land, the status stays null as for unknown signatures
+                    expired_signatures.push(sig.clone());
+                    return None;
+                }
+                let mut status = props.status?;
+                status.confirmations = confirmations(status.slot);
+                Some(status)
+            })
+            .collect::<Vec<_>>();
+
+        // landed transactions are forgotten once their blockhash expired
+        let not_found = signatures
+            .iter()
+            .zip(&sig_statuses)
+            .filter(|(_, status)| status.is_none())
+            .map(|(signature, _)| *signature)
+            .collect::<Vec<_>>();
+        if search_transaction_history && !not_found.is_empty() {
+            let history_statuses = self
+                .history
+                .get_transaction_statuses(&not_found)
+                .await
+                .map_err(|err| jsonrpsee::core::Error::Custom(err.to_string()))?;
+
+            for (signature, status) in signatures.iter().zip(sig_statuses.iter_mut()) {
+                let Some((slot, err)) = history_statuses.get(signature) else {
+                    continue;
+                };
+                // landed after all
+                let signature = signature.to_string();
+                expired_signatures.retain(|expired_signature| *expired_signature != signature);
+
+                let is_finalized = *slot <= finalized_block.slot;
+                *status = Some(TransactionStatus {
+                    slot: *slot,
+                    confirmations: confirmations(*slot),
+                    status: err.clone().map_or(Ok(()), Err),
+                    err: err.clone(),
+                    confirmation_status: Some(if is_finalized {
+                        TransactionConfirmationStatus::Finalized
+                    } else {
+                        TransactionConfirmationStatus::Confirmed
+                    }),
+                });
+            }
+        }
+
+        Ok(RpcSignatureStatusesResponse {
+            context: RpcResponseContext::new(finalized_block.slot),
             value: sig_statuses,
+            expired_signatures,
         })
     }
 
     async fn get_recent_prioritization_fees(
         &self,
-        _pubkey_strs: Option<Vec<String>>,
+        pubkey_strs: Option<Vec<String>>,
     ) -> crate::rpc::Result<Vec<RpcPrioritizationFee>> {
-        todo!()
+        RPC_GET_RECENT_PRIORITIZATION_FEES.inc();
+
+        let pubkey_strs = pubkey_strs.unwrap_or_default();
+        if pubkey_strs.len() > MAX_TX_ACCOUNT_LOCKS {
+            return Err(jsonrpsee::core::Error::Custom(format!(
+                "Too many inputs provided; max {MAX_TX_ACCOUNT_LOCKS}"
+            )));
+        }
+        let accounts = pubkey_strs
+            .iter()
+            .map(|pubkey_str| Pubkey::from_str(pubkey_str))
+            .collect::<Result<Vec<_>, _>>()
+            .map_err(|err| jsonrpsee::core::Error::Custom(format!("Invalid param: {err}")))?;
+
+        Ok(self
+            .prio_fees_service
+            .get_recent_prioritization_fees(&accounts)
+            .await
+            .into_iter()
+            .map(|(slot, prioritization_fee)| RpcPrioritizationFee {
+                slot,
+                prioritization_fee,
+            })
+            .collect())
     }
 
     async fn send_transaction(
@@ -332,7 +1164,10 @@ impl LiteRpcServer for LiteBridge {
         let SendTransactionConfig {
             encoding,
             max_retries,
+            min_context_slot,
         } = send_transaction_config.unwrap_or_default();
+        self.get_context_slot(CommitmentConfig::processed(), min_context_slot)
+            .await?;
 
         let expected_size = match encoding {
             encoding::BinaryEncoding::Base58 => MAX_BASE58_SIZE,
@@ -421,38 +1256,738 @@ impl LiteRpcServer for LiteBridge {
         Ok(airdrop_sig)
     }
 
+    async fn get_account_info(
+        &self,
+        pubkey_str: String,
+        config: Option<RpcAccountInfoConfig>,
+    ) -> crate::rpc::Result<RpcResponse<Option<UiAccount>>> {
+        RPC_GET_ACCOUNT_INFO.inc();
+
+        let pubkey = Pubkey::from_str(&pubkey_str)
+            .map_err(|err| jsonrpsee::core::Error::Custom(err.to_string()))?;
+        let config = config.unwrap_or_default();
+        // same default as solana rpc
+        let encoding = config.encoding.unwrap_or(UiAccountEncoding::Binary);
+        let commitment_config = config.commitment.unwrap_or_default();
+
+        let (slot, accounts) = self
+            .get_accounts(&[pubkey], commitment_config, config.min_context_slot)
+            .await?;
+        let mint_decimals = self
+            .get_token_mint_decimals(accounts.iter().flatten(), commitment_config, encoding)
+            .await?;
+
+        Ok(RpcResponse {
+            context: RpcResponseContext::new(slot),
+            value: accounts
+                .first()
+                .and_then(Option::as_ref)
+                .map(|data| encode_account(data, encoding, config.data_slice, &mint_decimals)),
+        })
+    }
+
+    async fn get_multiple_accounts(
+        &self,
+        pubkey_strs: Vec<String>,
+        config: Option<RpcAccountInfoConfig>,
+    ) -> crate::rpc::Result<RpcResponse<Vec<Option<UiAccount>>>> {
+        RPC_GET_MULTIPLE_ACCOUNTS.inc();
+
+        if pubkey_strs.len() > MAX_MULTIPLE_ACCOUNTS {
+            return Err(jsonrpsee::core::Error::Custom(format!(
+                "Too many inputs provided; max {MAX_MULTIPLE_ACCOUNTS}"
+            )));
+        }
+        let pubkeys = pubkey_strs
+            .iter()
+            .map(|pubkey_str| Pubkey::from_str(pubkey_str))
+            .collect::<Result<Vec<_>, _>>()
+            .map_err(|err| jsonrpsee::core::Error::Custom(err.to_string()))?;
+        let config = config.unwrap_or_default();
+        let encoding = config.encoding.unwrap_or(UiAccountEncoding::Base64);
+        let commitment_config = config.commitment.unwrap_or_default();
+
+        let (slot, accounts) = self
+            .get_accounts(&pubkeys, commitment_config, config.min_context_slot)
+            .await?;
+        let mint_decimals = self
+            .get_token_mint_decimals(accounts.iter().flatten(), commitment_config, encoding)
+            .await?;
+
+        Ok(RpcResponse {
+            context: RpcResponseContext::new(slot),
+            value: accounts
+                .iter()
+                .map(|data| {
+                    data.as_ref().map(|data| {
+                        encode_account(data, encoding, config.data_slice, &mint_decimals)
+                    })
+                })
+                .collect(),
+        })
+    }
+
+    async fn get_balance(
+        &self,
+        pubkey_str: String,
+        config: Option<RpcContextConfig>,
+    ) -> crate::rpc::Result<RpcResponse<u64>> {
+        RPC_GET_BALANCE.inc();
+
+        let pubkey = Pubkey::from_str(&pubkey_str)
+            .map_err(|err| jsonrpsee::core::Error::Custom(err.to_string()))?;
+        let config = config.unwrap_or_default();
+
+        let (slot, mut accounts) = self
+            .get_accounts(
+                &[pubkey],
+                config.commitment.unwrap_or_default(),
+                config.min_context_slot,
+            )
+            .await?;
+
+        Ok(RpcResponse {
+            context: RpcResponseContext::new(slot),
+            value: accounts
+                .pop()
+                .flatten()
+                .map_or(0, |data| data.account.lamports()),
+        })
+    }
+
+    async fn get_program_accounts(
+        &self,
+        program_id_str: String,
+        config: Option<RpcProgramAccountsConfig>,
+    ) -> crate::rpc::Result<OptionalContext<Vec<RpcKeyedAccount>>> {
+        RPC_GET_PROGRAM_ACCOUNTS.inc();
+
+        let program_id = Pubkey::from_str(&program_id_str)
+            .map_err(|err| jsonrpsee::core::Error::Custom(err.to_string()))?;
+        let config = config.unwrap_or_default();
+        let commitment_config = config.account_config.commitment.unwrap_or_default();
+        let filters = prepare_filters(config.filters.clone().unwrap_or_default())
+            .map_err(|err| jsonrpsee::core::Error::Custom(format!("Invalid Request: {err}")))?;
+
+        let Some(program_accounts) = self.data_cache.program_accounts.get_program_accounts(
+            &program_id,
+            commitment_config,
+            &filters,
+        ) else {
+            // program not indexed (yet)
+            return self
+                .forward(
+                    RpcRequest::GetProgramAccounts,
+                    serde_json::json!([program_id_str, config]),
+                )
+                .await;
+        };
+
+        let slot = self
+            .get_context_slot(commitment_config, config.account_config.min_context_slot)
+            .await?;
+        // same default as solana rpc
+        let encoding = config
+            .account_config
+            .encoding
+            .unwrap_or(UiAccountEncoding::Binary);
+        let mint_decimals = self
+            .get_token_mint_decimals(&program_accounts, commitment_config, encoding)
+            .await?;
+        let keyed_accounts = program_accounts
+            .iter()
+            .map(|data| RpcKeyedAccount {
+                pubkey: data.pubkey.to_string(),
+                account: encode_account(
+                    data,
+                    encoding,
+                    config.account_config.data_slice,
+                    &mint_decimals,
+                ),
+            })
+            .collect();
+
+        if config.with_context.unwrap_or_default() {
+            Ok(OptionalContext::Context(RpcResponse {
+                context: RpcResponseContext::new(slot),
+                value: keyed_accounts,
+            }))
+        } else {
+            Ok(OptionalContext::NoContext(keyed_accounts))
+        }
+    }
+
+    async fn get_token_accounts_by_owner(
+        &self,
+        owner_str: String,
+        filter: RpcTokenAccountsFilter,
+        config: Option<RpcAccountInfoConfig>,
+    ) -> crate::rpc::Result<RpcResponse<Vec<RpcKeyedAccount>>> {
+        RPC_GET_TOKEN_ACCOUNTS_BY_OWNER.inc();
+
+        let owner = Pubkey::from_str(&owner_str)
+            .map_err(|err| jsonrpsee::core::Error::Custom(format!("Invalid param: {err}")))?;
+        self.get_token_accounts(
+            RpcRequest::GetTokenAccountsByOwner,
+            TokenAccountKey::Owner(owner),
+            filter,
+            config,
+        )
+        .await
+    }
+
+    async fn get_token_accounts_by_delegate(
+        &self,
+        delegate_str: String,
+        filter: RpcTokenAccountsFilter,
+        config: Option<RpcAccountInfoConfig>,
+    ) -> crate::rpc::Result<RpcResponse<Vec<RpcKeyedAccount>>> {
+        RPC_GET_TOKEN_ACCOUNTS_BY_DELEGATE.inc();
+
+        let delegate = Pubkey::from_str(&delegate_str)
+            .map_err(|err| jsonrpsee::core::Error::Custom(format!("Invalid param: {err}")))?;
+        self.get_token_accounts(
+            RpcRequest::GetTokenAccountsByDelegate,
+            TokenAccountKey::Delegate(delegate),
+            filter,
+            config,
+        )
+        .await
+    }
+
+    async fn get_token_account_balance(
+        &self,
+        pubkey_str: String,
+        commitment: Option<CommitmentConfig>,
+    ) -> crate::rpc::Result<RpcResponse<UiTokenAmount>> {
+        RPC_GET_TOKEN_ACCOUNT_BALANCE.inc();
+
+        let pubkey = Pubkey::from_str(&pubkey_str)
+            .map_err(|err| jsonrpsee::core::Error::Custom(format!("Invalid param: {err}")))?;
+        let commitment_config = commitment.unwrap_or_default();
+
+        let (slot, mut accounts) = self
+            .get_accounts(&[pubkey], commitment_config, None)
+            .await?;
+        let Some(data) = accounts.pop().flatten() else {
+            return Err(jsonrpsee::core::Error::Custom(
+                "Invalid param: could not find account".to_string(),
+            ));
+        };
+        let Some(token_account) = TokenAccount::parse(&data) else {
+            return Err(jsonrpsee::core::Error::Custom(
+                "Invalid param: not a Token account".to_string(),
+            ));
+        };
+        let Some(decimals) = self
+            .get_mint_decimals(&[token_account.mint], commitment_config)
+            .await?
+            .remove(&token_account.mint)
+        else {
+            return Err(jsonrpsee::core::Error::Custom(
+                "Invalid param: could not find mint".to_string(),
+            ));
+        };
+
+        Ok(RpcResponse {
+            context: RpcResponseContext::new(slot),
+            value: token_amount_to_ui_amount(token_account.amount, decimals),
+        })
+    }
+
+    async fn get_token_largest_accounts(
+        &self,
+        mint_str: String,
+        commitment: Option<CommitmentConfig>,
+    ) -> crate::rpc::Result<RpcResponse<Vec<RpcTokenAccountBalance>>> {
+        RPC_GET_TOKEN_LARGEST_ACCOUNTS.inc();
+
+        let mint = Pubkey::from_str(&mint_str)
+            .map_err(|err| jsonrpsee::core::Error::Custom(format!("Invalid param: {err}")))?;
+        let commitment_config = commitment.unwrap_or_default();
+
+        let Some(mut token_accounts) = self
+            .data_cache
+            .program_accounts
+            .get_token_accounts(&TokenAccountKey::Mint(mint), commitment_config)
+        else {
+            return self
+                .forward(
+                    RpcRequest::GetTokenLargestAccounts,
+                    serde_json::json!([mint_str, commitment]),
+                )
+                .await;
+        };
+        let Some(decimals) = self
+            .get_mint_decimals(&[mint], commitment_config)
+            .await?
+            .remove(&mint)
+        else {
+            return Err(jsonrpsee::core::Error::Custom(
+                "Invalid param: mint could not be unpacked".to_string(),
+            ));
+        };
+
+        let slot = self.get_context_slot(commitment_config, None).await?;
+        token_accounts.sort_by(|(_, first), (_, second)| second.amount.cmp(&first.amount));
+        Ok(RpcResponse {
+            context: RpcResponseContext::new(slot),
+            value: token_accounts
+                .into_iter()
+                .take(NUM_LARGEST_ACCOUNTS)
+                .map(|(data, token_account)| RpcTokenAccountBalance {
+                    address: data.pubkey.to_string(),
+                    amount: token_amount_to_ui_amount(token_account.amount, decimals),
+                })
+                .collect(),
+        })
+    }
+
     async fn program_subscribe(
         &self,
-        _pending: PendingSubscriptionSink,
-        _pubkey_str: String,
-        _config: Option<RpcProgramAccountsConfig>,
+        pending: PendingSubscriptionSink,
+        pubkey_str: String,
+        config: Option<RpcProgramAccountsConfig>,
     ) -> SubscriptionResult {
-        todo!()
+        RPC_PROGRAM_SUBSCRIBE.inc();
+
+        let Some(account_notifier) = &self.account_notifier else {
+            pending
+                .reject(jsonrpsee::core::Error::Custom(
+                    "Account streaming is not available; requires grpc".to_string(),
+                ))
+                .await;
+            return Ok(());
+        };
+        let Ok(program_id) = Pubkey::from_str(&pubkey_str) else {
+            pending
+                .reject(jsonrpsee::core::Error::Custom(
+                    "Invalid Request: Invalid pubkey provided".to_string(),
+                ))
+                .await;
+            return Ok(());
+        };
+
+        let config = config.unwrap_or_default();
+        let filters = match prepare_filters(config.filters.unwrap_or_default()) {
+            Ok(filters) => filters,
+            Err(err) => {
+                pending
+                    .reject(jsonrpsee::core::Error::Custom(format!(
+                        "Invalid Request: {err}"
+                    )))
+                    .await;
+                return Ok(());
+            }
+        };
+        let commitment_config = config.account_config.commitment.unwrap_or_default();
+        // same default as solana rpc
+        let encoding = config
+            .account_config
+            .encoding
+            .unwrap_or(UiAccountEncoding::Binary);
+        let data_slice = config.account_config.data_slice;
+
+        let sink = pending.accept().await?;
+
+        let mut account_notifier = account_notifier.resubscribe();
+        let account_filters = &self.data_cache.account_filters;
+        account_filters.subscribe_program(program_id);
+        let result = loop {
+            let message = tokio::select! {
+                message = recv_subscription_message(&mut account_notifier, "program") => message,
+                _ = sink.closed() => break Ok(()),
+            };
+
+            let AccountNotificationMessage { data, commitment } = match message {
+                Ok(Some(message)) => message,
+                Ok(None) => break Ok(()),
+                Err(err) => break Err(err),
+            };
+
+            if commitment.commitment != commitment_config.commitment
+                || data.account.owner() != &program_id
+                || !data.allows(&filters)
+            {
+                continue;
+            }
+
+            let mint_decimals = self
+                .get_subscription_mint_decimals(&data, commitment_config, encoding)
+                .await;
+            let result_message = jsonrpsee::SubscriptionMessage::from_json(&RpcResponse {
+                context: RpcResponseContext::new(data.updated_slot),
+                value: RpcKeyedAccount {
+                    pubkey: data.pubkey.to_string(),
+                    account: encode_account(&data, encoding, data_slice, &mint_decimals),
+                },
+            });
+            if sink.send(result_message.unwrap()).await.is_err() {
+                break Ok(());
+            }
+        };
+
+        debug!("Stopping program subscription task for {}", program_id);
+        account_filters.unsubscribe_program(&program_id);
+        result
     }
 
-    async fn slot_subscribe(&self, _pending: PendingSubscriptionSink) -> SubscriptionResult {
-        todo!()
+    async fn account_subscribe(
+        &self,
+        pending: PendingSubscriptionSink,
+        pubkey_str: String,
+        config: Option<RpcAccountInfoConfig>,
+    ) -> SubscriptionResult {
+        RPC_ACCOUNT_SUBSCRIBE.inc();
+
+        let Some(account_notifier) = &self.account_notifier else {
+            pending
+                .reject(jsonrpsee::core::Error::Custom(
+                    "Account streaming is not available; requires grpc".to_string(),
+                ))
+                .await;
+            return Ok(());
+        };
+        let Ok(pubkey) = Pubkey::from_str(&pubkey_str) else {
+            pending
+                .reject(jsonrpsee::core::Error::Custom(
+                    "Invalid Request: Invalid pubkey provided".to_string(),
+                ))
+                .await;
+            return Ok(());
+        };
+
+        let config = config.unwrap_or_default();
+        let commitment_config = config.commitment.unwrap_or_default();
+        // same default as solana rpc
+        let encoding = config.encoding.unwrap_or(UiAccountEncoding::Binary);
+        let data_slice = config.data_slice;
+
+        let sink = pending.accept().await?;
+
+        let mut account_notifier = account_notifier.resubscribe();
+        let account_filters = &self.data_cache.account_filters;
+        account_filters.subscribe_account(pubkey);
+        let result = loop {
+            let message = tokio::select! {
+                message = recv_subscription_message(&mut account_notifier, "account") => message,
+                _ = sink.closed() => break Ok(()),
+            };
+
+            let AccountNotificationMessage { data, commitment } = match message {
+                Ok(Some(message)) => message,
+                Ok(None) => break Ok(()),
+                Err(err) => break Err(err),
+            };
+
+            if data.pubkey != pubkey || commitment.commitment != commitment_config.commitment {
+                continue;
+            }
+
+            let mint_decimals = self
+                .get_subscription_mint_decimals(&data, commitment_config, encoding)
+                .await;
+            let result_message = jsonrpsee::SubscriptionMessage::from_json(&RpcResponse {
+                context: RpcResponseContext::new(data.updated_slot),
+                value: encode_account(&data, encoding, data_slice, &mint_decimals),
+            });
+            if sink.send(result_message.unwrap()).await.is_err() {
+                break Ok(());
+            }
+        };
+
+        debug!("Stopping account subscription task for {}", pubkey);
+        account_filters.unsubscribe_account(&pubkey);
+        result
+    }
+
+    async fn slot_subscribe(&self, pending: PendingSubscriptionSink) -> SubscriptionResult {
+        RPC_SLOT_SUBSCRIBE.inc();
+        let sink = pending.accept().await?;
+
+        let mut slot_notifier = self.slot_notifier.resubscribe();
+        let mut previous = SlotNotification::default();
+        loop {
+            let message = tokio::select! {
+                message = recv_subscription_message(&mut slot_notifier, "slot") => message?,
+                _ = sink.closed() => {
+                    debug!("Stopping slot subscription task on disconnect");
+                    return Ok(());
+                }
+            };
+            let Some(slot_notification) = message else {
+                return Ok(());
+            };
+
+            let slot_info = slot_notification.slot_info(&previous);
+            previous = slot_notification;
+            let Some(slot_info) = slot_info else {
+                continue;
+            };
+
+            let result_message = jsonrpsee::SubscriptionMessage::from_json(&slot_info);
+            if sink.send(result_message.unwrap()).await.is_err() {
+                debug!("Stopping slot subscription task on disconnect");
+                return Ok(());
+            }
+        }
     }
 
     async fn block_subscribe(
         &self,
-        _pending: PendingSubscriptionSink,
-        _filter: RpcBlockSubscribeFilter,
-        _config: Option<RpcBlockSubscribeConfig>,
+        pending: PendingSubscriptionSink,
+        filter: RpcBlockSubscribeFilter,
+        config: Option<RpcBlockSubscribeConfig>,
+    ) -> SubscriptionResult {
+        RPC_BLOCK_SUBSCRIBE.inc();
+
+        let mentions_account = match filter {
+            RpcBlockSubscribeFilter::All => None,
+            RpcBlockSubscribeFilter::MentionsAccountOrProgram(pubkey_str) => {
+                match Pubkey::from_str(&pubkey_str) {
+                    Ok(pubkey) => Some(pubkey),
+                    Err(_) => {
+                        pending
+                            .reject(jsonrpsee::core::Error::Custom(
+                                "Invalid Request: Invalid pubkey provided".to_string(),
+                            ))
+                            .await;
+                        return Ok(());
+                    }
+                }
+            }
+        };
+
+        // same defaults as solana rpc
+        let config = config.unwrap_or_default();
+        let commitment_config = config.commitment.unwrap_or_default();
+        let encoding = config.encoding.unwrap_or(UiTransactionEncoding::Base64);
+        let transaction_details = config.transaction_details.unwrap_or_default();
+        let show_rewards = config.show_rewards.unwrap_or_default();
+        let max_supported_transaction_version = config.max_supported_transaction_version;
+
+        let sink = pending.accept().await?;
+
+        let mut blocks_notifier = self.blocks_notifier.resubscribe();
+        loop {
+            let block = tokio::select! {
+                block = recv_subscription_message(&mut blocks_notifier, "block") => block?,
+                _ = sink.closed() => {
+                    debug!("Stopping block subscription task on disconnect");
+                    return Ok(());
+                }
+            };
+            let Some(block) = block else {
+                return Ok(());
+            };
+
+            if block.commitment_config.commitment != commitment_config.commitment {
+                continue;
+            }
+
+            let block = match &mentions_account {
+                Some(account) => {
+                    let block = block.with_transactions_mentioning(account);
+                    if block.transactions.is_empty() {
+                        continue;
+                    }
+                    block
+                }
+                None => block,
+            };
+
+            let options = BlockEncodingOptions {
+                transaction_details,
+                show_rewards,
+                max_supported_transaction_version,
+            };
+            let block_update = match block.encode_with_options(encoding, options) {
+                Ok(ui_block) => RpcBlockUpdate {
+                    slot: block.slot,
+                    block: Some(ui_block),
+                    err: None,
+                },
+                Err(err) => match err.downcast_ref::<EncodeError>() {
+                    Some(EncodeError::UnsupportedTransactionVersion(version)) => RpcBlockUpdate {
+                        slot: block.slot,
+                        block: None,
+                        err: Some(RpcBlockUpdateError::UnsupportedTransactionVersion(*version)),
+                    },
+                    None => {
+                        error!("Cannot encode block {} - skipping: {:?}", block.slot, err);
+                        continue;
+                    }
+                },
+            };
+
+            let result_message = jsonrpsee::SubscriptionMessage::from_json(&RpcResponse {
+                context: RpcResponseContext::new(block.slot),
+                value: block_update,
+            });
+            if sink.send(result_message.unwrap()).await.is_err() {
+                debug!("Stopping block subscription task on disconnect");
+                return Ok(());
+            }
+        }
+    }
+
+    async fn transaction_subscribe(
+        &self,
+        pending: PendingSubscriptionSink,
+        filter: RpcTransactionSubscribeFilter,
+        config: Option<RpcTransactionSubscribeConfig>,
     ) -> SubscriptionResult {
-        todo!()
+        RPC_TRANSACTION_SUBSCRIBE.inc();
+
+        let filter = match TransactionFilter::try_from(filter) {
+            Ok(filter) => filter,
+            Err(err) => {
+                pending.reject(jsonrpsee::core::Error::Custom(err)).await;
+                return Ok(());
+            }
+        };
+
+        // same defaults as blockSubscribe
+        let config = config.unwrap_or_default();
+        let commitment_config = config.commitment.unwrap_or_default();
+        let encoding = config.encoding.unwrap_or(UiTransactionEncoding::Base64);
+        let transaction_details = config.transaction_details.unwrap_or_default();
+        let show_rewards = config.show_rewards.unwrap_or_default();
+        let max_supported_transaction_version = config.max_supported_transaction_version;
+
+        let sink = pending.accept().await?;
+
+        let mut blocks_notifier = self.blocks_notifier.resubscribe();
+        loop {
+            let block = tokio::select! {
+                block = recv_subscription_message(&mut blocks_notifier, "transaction") => block?,
+                _ = sink.closed() => {
+                    debug!("Stopping transaction subscription task on disconnect");
+                    return Ok(());
+                }
+            };
+            let Some(block) = block else {
+                return Ok(());
+            };
+
+            if block.commitment_config.commitment != commitment_config.commitment {
+                continue;
+            }
+
+            for tx in block.transactions.iter().filter(|tx| filter.matches(tx)) {
+                let options = BlockEncodingOptions {
+                    transaction_details,
+                    show_rewards,
+                    max_supported_transaction_version,
+                };
+                let transaction = match tx.encode_with_options(encoding, options) {
+                    Ok(transaction) => transaction,
+                    Err(err) => {
+                        // e.g. version above maxSupportedTransactionVersion
+                        debug!(
+                            "Cannot encode transaction {} - skipping: {:?}",
+                            tx.signature, err
+                        );
+                        continue;
+                    }
+                };
+
+                let result_message = jsonrpsee::SubscriptionMessage::from_json(&RpcResponse {
+                    context: RpcResponseContext::new(block.slot),
+                    value: RpcTransactionUpdate {
+                        signature: tx.signature.clone(),
+                        slot: block.slot,
+                        transaction,
+                    },
+                });
+                if sink.send(result_message.unwrap()).await.is_err() {
+                    debug!("Stopping transaction subscription task on disconnect");
+                    return Ok(());
+                }
+            }
+        }
     }
 
     async fn logs_subscribe(
         &self,
-        _pending: PendingSubscriptionSink,
-        _filter: RpcTransactionLogsFilter,
-        _config: Option<RpcTransactionLogsConfig>,
+        pending: PendingSubscriptionSink,
+        filter: RpcTransactionLogsFilter,
+        config: Option<RpcTransactionLogsConfig>,
     ) -> SubscriptionResult {
-        todo!()
+        RPC_LOGS_SUBSCRIBE.inc();
+
+        let (include_votes, mentions_account) = match filter {
+            RpcTransactionLogsFilter::All => (false, None),
+            RpcTransactionLogsFilter::AllWithVotes => (true, None),
+            RpcTransactionLogsFilter::Mentions(addresses) => {
+                // same restriction as solana rpc
+                let pubkey = match addresses.as_slice() {
+                    [address] => Pubkey::from_str(address)
+                        .map_err(|_| "Invalid Request: Invalid pubkey provided".to_string()),
+                    _ => Err("Invalid Request: Only 1 address supported".to_string()),
+                };
+                match pubkey {
+                    Ok(pubkey) => (true, Some(pubkey)),
+                    Err(err) => {
+                        pending.reject(jsonrpsee::core::Error::Custom(err)).await;
+                        return Ok(());
+                    }
+                }
+            }
+        };
+        let commitment_config = config
+            .and_then(|config| config.commitment)
+            .unwrap_or_default();
+
+        let sink = pending.accept().await?;
+
+        let mut blocks_notifier = self.blocks_notifier.resubscribe();
+        loop {
+            let block = tokio::select! {
+                block = recv_subscription_message(&mut blocks_notifier, "logs") => block?,
+                _ = sink.closed() => {
+                    debug!("Stopping logs subscription task on disconnect");
+                    return Ok(());
+                }
+            };
+            let Some(block) = block else {
+                return Ok(());
+            };
+
+            if block.commitment_config.commitment != commitment_config.commitment {
+                continue;
+            }
+
+            for tx in &block.transactions {
+                if tx.is_vote && !include_votes {
+                    continue;
+                }
+                if let Some(account) = &mentions_account {
+                    let mentioned = tx
+                        .account_keys()
+                        .map(|account_keys| account_keys.contains(account))
+                        .unwrap_or_default();
+                    if !mentioned {
+                        continue;
+                    }
+                }
+
+                let result_message = jsonrpsee::SubscriptionMessage::from_json(&RpcResponse {
+                    context: RpcResponseContext::new(block.slot),
+                    value: RpcLogsResponse {
+                        signature: tx.signature.clone(),
+                        err: tx.err.clone(),
+                        logs: tx.log_messages.clone().unwrap_or_default(),
+                    },
+                });
+                if sink.send(result_message.unwrap()).await.is_err() {
+                    debug!("Stopping logs subscription task on disconnect");
+                    return Ok(());
+                }
+            }
+        }
     }
 
-    // WARN: enable_received_notification: bool is ignored
     async fn signature_subscribe(
         &self,
         pending: PendingSubscriptionSink,
@@ -462,25 +1997,108 @@ impl LiteRpcServer for LiteBridge {
         RPC_SIGNATURE_SUBSCRIBE.inc();
         let sink = pending.accept().await?;
 
-        let jsonrpsee_sink = JsonRpseeSubscriptionHandlerSink::new(sink);
-        self.data_cache.tx_subs.signature_subscribe(
-            signature,
-            config.commitment.unwrap_or_default(),
-            Arc::new(jsonrpsee_sink),
-        );
+        let (jsonrpsee_sink, close_with_error) = JsonRpseeSubscriptionHandlerSink::new(sink);
+        self.data_cache
+            .tx_subs
+            .signature_subscribe(
+                signature,
+                config.commitment.unwrap_or_default(),
+                Arc::new(jsonrpsee_sink),
+                config.enable_received_notification.unwrap_or_default(),
+                &self.data_cache.txs,
+            )
+            .await;
 
-        Ok(())
+        // runs until the subscription store drops the sink, after the notification or when it closes the
+        // subscription with an error
+        match close_with_error.await {
+            Ok(error) => Err(error.into()),
+            Err(_) => Ok(()),
+        }
     }
 
-    async fn slot_updates_subscribe(
-        &self,
-        _pending: PendingSubscriptionSink,
-    ) -> SubscriptionResult {
-        todo!()
+    async fn slot_updates_subscribe(&self, pending: PendingSubscriptionSink) -> SubscriptionResult {
+        RPC_SLOT_UPDATES_SUBSCRIBE.inc();
+        let sink = pending.accept().await?;
+
+        let mut slot_notifier = self.slot_notifier.resubscribe();
+        let mut previous = SlotNotification::default();
+        loop {
+            let message = tokio::select! {
+                message = recv_subscription_message(&mut slot_notifier, "slot updates") => message?,
+                _ = sink.closed() => {
+                    debug!("Stopping slot updates subscription task on disconnect");
+                    return Ok(());
+                }
+            };
+            let Some(slot_notification) = message else {
+                return Ok(());
+            };
+
+            let timestamp = SystemTime::now()
+                .duration_since(UNIX_EPOCH)
+                .unwrap_or_default()
+                .as_millis() as u64;
+            let slot_updates = slot_notification.slot_updates(&previous, timestamp);
+            previous = slot_notification;
+
+            for slot_update in slot_updates {
+                let result_message = jsonrpsee::SubscriptionMessage::from_json(&slot_update);
+                if sink.send(result_message.unwrap()).await.is_err() {
+                    debug!("Stopping slot updates subscription task on disconnect");
+                    return Ok(());
+                }
+            }
+        }
     }
 
-    async fn vote_subscribe(&self, _pending: PendingSubscriptionSink) -> SubscriptionResult {
-        todo!()
+    async fn vote_subscribe(&self, pending: PendingSubscriptionSink) -> SubscriptionResult {
+        RPC_VOTE_SUBSCRIBE.inc();
+        let sink = pending.accept().await?;
+
+        let mut blocks_notifier = self.blocks_notifier.resubscribe();
+        // votes are published from the first block seen for a slot (processed if the source provides it)
+        let mut published_slots = BTreeSet::new();
+        loop {
+            let block = tokio::select! {
+                block = recv_subscription_message(&mut blocks_notifier, "vote") => block?,
+                _ = sink.closed() => {
+                    debug!("Stopping vote subscription task on disconnect");
+                    return Ok(());
+                }
+            };
+            let Some(block) = block else {
+                return Ok(());
+            };
+
+            if block.commitment_config.is_finalized() || !published_slots.insert(block.slot) {
+                continue;
+            }
+            while published_slots.len() > VOTE_SUBSCRIBE_SLOTS_TO_RETAIN {
+                published_slots.pop_first();
+            }
+
+            for tx in block.transactions.iter().filter(|tx| tx.is_vote) {
+                let votes = match tx.get_votes() {
+                    Ok(votes) => votes,
+                    Err(err) => {
+                        warn!(
+                            "Cannot parse votes of transaction {}: {:?}",
+                            tx.signature, err
+                        );
+                        continue;
+                    }
+                };
+
+                for vote in votes {
+                    let result_message = jsonrpsee::SubscriptionMessage::from_json(&vote);
+                    if sink.send(result_message.unwrap()).await.is_err() {
+                        debug!("Stopping vote subscription task on disconnect");
+                        return Ok(());
+                    }
+                }
+            }
+        }
     }
 
     async fn get_leader_schedule(
@@ -518,19 +2136,48 @@ impl LiteRpcServer for LiteBridge {
 
     async fn get_vote_accounts(
         &self,
-        _config: Option<RpcGetVoteAccountsConfig>,
+        config: Option<RpcGetVoteAccountsConfig>,
     ) -> crate::rpc::Result<RpcVoteAccountStatus> {
-        todo!()
+        RPC_GET_VOTE_ACCOUNTS.inc();
+
+        let config = GetVoteAccountsConfig::try_from(config.unwrap_or_default())
+            .map_err(|err| jsonrpsee::core::Error::Custom(format!("Invalid vote pubkey: {err}")))?;
+
+        if let Some(vote_accounts_sender) = &self.vote_accounts_sender {
+            let (tx, rx) = oneshot::channel();
+            vote_accounts_sender
+                .send((config, tx))
+                .await
+                .map_err(|err| {
+                    jsonrpsee::core::Error::Custom(format!("error during query processing:{err}"))
+                })?;
+            return rx.await.map_err(|err| {
+                jsonrpsee::core::Error::Custom(format!("error during query processing:{err}"))
+            });
+        }
+
+        // same default commitment as the stake and vote engine
+        let commitment_config = config.commitment.unwrap_or(CommitmentConfig::confirmed());
+        let current_slot = self
+            .data_cache
+            .block_information_store
+            .get_latest_block(commitment_config)
+            .await
+            .slot;
+        self.data_cache
+            .vote_accounts
+            .get_vote_accounts(current_slot, &config)
+            .await
+            .ok_or_else(|| {
+                jsonrpsee::core::Error::Custom("Vote accounts not polled yet".to_string())
+            })
     }
 
     async fn get_latest_block_priofees(&self) -> crate::rpc::Result<RpcResponse<PrioFeesStats>> {
         match self.prio_fees_service.get_latest_priofees().await {
             Some((confirmation_slot, priofees)) => {
                 return Ok(RpcResponse {
-                    context: RpcResponseContext {
-                        slot: confirmation_slot,
-                        api_version: None,
-                    },
+                    context: RpcResponseContext::new(confirmation_slot),
                     value: priofees,
                 });
             }
@@ -550,51 +2197,34 @@ impl LiteRpcServer for LiteBridge {
         let sink = pending.accept().await?;
 
         let mut block_fees_stream = self.prio_fees_service.block_fees_stream.subscribe();
-        tokio::spawn(async move {
-            RPC_BLOCK_PRIOFEES_SUBSCRIBE.inc();
-
-            'recv_loop: loop {
-                match block_fees_stream.recv().await {
-                    Ok(PrioFeesUpdateMessage {
-                        slot: confirmation_slot,
-                        priofees_stats,
-                    }) => {
-                        let result_message =
-                            jsonrpsee::SubscriptionMessage::from_json(&RpcResponse {
-                                context: RpcResponseContext {
-                                    slot: confirmation_slot,
-                                    api_version: None,
-                                },
-                                value: priofees_stats,
-                            });
-
-                        match sink.send(result_message.unwrap()).await {
-                            Ok(()) => {
-                                // success
-                                continue 'recv_loop;
-                            }
-                            Err(DisconnectError(_subscription_message)) => {
-                                debug!("Stopping subscription task on disconnect");
-                                return;
-                            }
-                        };
-                    }
-                    Err(Lagged(lagged)) => {
-                        // this usually happens if there is one "slow receiver", see https://docs.rs/tokio/latest/tokio/sync/broadcast/index.html#lagging
-                        warn!(
-                            "subscriber laggs some({}) priofees update messages - continue",
-                            lagged
-                        );
-                        continue 'recv_loop;
-                    }
-                    Err(Closed) => {
-                        error!("failed to receive block, sender closed - aborting");
-                        return;
-                    }
-                }
+        RPC_BLOCK_PRIOFEES_SUBSCRIBE.inc();
+
+        loop {
+            let Some(PrioFeesUpdateMessage {
+                slot: confirmation_slot,
+                priofees_stats,
+            }) = recv_subscription_message(&mut block_fees_stream, "priofees").await?
+            else {
+                return Ok(());
+            };
+
+            let result_message = jsonrpsee::SubscriptionMessage::from_json(&RpcResponse {
+                context: RpcResponseContext::new(confirmation_slot),
+                value: priofees_stats,
+            });
+
+            if let Err(DisconnectError(_subscription_message)) =
+                sink.send(result_message.unwrap()).await
+            {
+                debug!("Stopping subscription task on disconnect");
+                return Ok(());
             }
-        });
+        }
+    }
+
+    async fn get_cluster_version_summary(&self) -> crate::rpc::Result<Vec<ClusterVersionCount>> {
+        RPC_GET_CLUSTER_VERSION_SUMMARY.inc();
 
-        Ok(())
+        Ok(self.data_cache.cluster_info.get_version_summary())
     }
 }
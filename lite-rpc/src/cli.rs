@@ -1,12 +1,13 @@
 use std::env;
 
 use crate::{
-    DEFAULT_FANOUT_SIZE, DEFAULT_GRPC_ADDR, DEFAULT_RETRY_TIMEOUT, DEFAULT_RPC_ADDR,
-    DEFAULT_WS_ADDR, MAX_RETRIES,
+    passthrough::PassthroughConfig, DEFAULT_FANOUT_SIZE, DEFAULT_GRPC_ADDR, DEFAULT_RETRY_TIMEOUT,
+    DEFAULT_RPC_ADDR, DEFAULT_WS_ADDR, MAX_RETRIES,
 };
 use anyhow::Context;
 use clap::Parser;
 use dotenv::dotenv;
+use solana_lite_rpc_core::stores::account_store::DEFAULT_ACCOUNT_CACHE_CAPACITY;
 use solana_lite_rpc_history::postgres::postgres_config::PostgresSessionConfig;
 
 #[derive(Parser, Debug, Clone)]
@@ -21,6 +22,9 @@ pub struct Args {
 pub struct Config {
     #[serde(default = "Config::default_rpc_addr")]
     pub rpc_addr: String,
+    /// more upstream rpc endpoints, requests fail over to them when rpc_addr is degraded
+    #[serde(default)]
+    pub rpc_addrs: Vec<String>,
     #[serde(default = "Config::default_ws_addr")]
     pub ws_addr: String,
     #[serde(default = "Config::default_lite_rpc_http_addr")]
@@ -64,9 +68,32 @@ pub struct Config {
     #[serde(default)]
     pub grpc_x_token4: Option<String>,
 
+    /// accounts always kept in the account cache (requires grpc)
+    #[serde(default)]
+    pub cached_accounts: Vec<String>,
+    /// programs whose accounts are always kept in the account cache (requires grpc)
+    #[serde(default)]
+    pub cached_programs: Vec<String>,
+    #[serde(default = "Config::default_account_cache_capacity")]
+    pub account_cache_capacity: usize,
+    /// programs whose accounts are all indexed to serve getProgramAccounts (requires grpc)
+    #[serde(default)]
+    pub indexed_programs: Vec<String>,
+    /// indexes spl token and token-2022 to serve getTokenAccountsByOwner/Delegate and getTokenLargestAccounts (requires grpc)
+    #[serde(default)]
+    pub index_token_accounts: bool,
+
     /// postgres config
     #[serde(default)]
     pub postgres: Option<PostgresSessionConfig>,
+
+    /// postgres config of the history block store (read path)
+    #[serde(default)]
+    pub block_store_postgres: Option<PostgresSessionConfig>,
+
+    /// forwarding of the methods lite-rpc does not implement to rpc_addr
+    #[serde(default)]
+    pub passthrough: PassthroughConfig,
 }
 
 impl Config {
@@ -101,6 +128,10 @@ impl Config {
 
         config.rpc_addr = env::var("RPC_ADDR").unwrap_or(config.rpc_addr);
 
+        config.rpc_addrs = env::var("RPC_ADDRS")
+            .map(|addrs| addrs.split(',').map(str::to_string).collect())
+            .unwrap_or(config.rpc_addrs);
+
         config.ws_addr = env::var("WS_ADDR").unwrap_or(config.ws_addr);
 
         config.lite_rpc_http_addr =
@@ -173,8 +204,40 @@ impl Config {
             .map(Some)
             .unwrap_or(config.grpc_x_token4);
 
+        config.cached_accounts = env::var("CACHED_ACCOUNTS")
+            .map(|accounts| accounts.split(',').map(str::to_string).collect())
+            .unwrap_or(config.cached_accounts);
+
+        config.cached_programs = env::var("CACHED_PROGRAMS")
+            .map(|programs| programs.split(',').map(str::to_string).collect())
+            .unwrap_or(config.cached_programs);
+
+        config.account_cache_capacity = env::var("ACCOUNT_CACHE_CAPACITY")
+            .map(|capacity| capacity.parse().unwrap())
+            .unwrap_or(config.account_cache_capacity);
+
+        config.indexed_programs = env::var("INDEXED_PROGRAMS")
+            .map(|programs| programs.split(',').map(str::to_string).collect())
+            .unwrap_or(config.indexed_programs);
+
+        config.index_token_accounts = env::var("INDEX_TOKEN_ACCOUNTS")
+            .map(|_| true)
+            .unwrap_or(config.index_token_accounts);
+
         config.postgres = PostgresSessionConfig::new_from_env()?.or(config.postgres);
 
+        config.passthrough.enabled = env::var("ENABLE_PASSTHROUGH")
+            .map(|_| true)
+            .unwrap_or(config.passthrough.enabled);
+
+        config.passthrough.allowed_methods = env::var("PASSTHROUGH_ALLOWED_METHODS")
+            .map(|methods| methods.split(',').map(str::to_string).collect())
+            .unwrap_or(config.passthrough.allowed_methods);
+
+        config.passthrough.denied_methods = env::var("PASSTHROUGH_DENIED_METHODS")
+            .map(|methods| methods.split(',').map(str::to_string).collect())
+            .unwrap_or(config.passthrough.denied_methods);
+
         Ok(config)
     }
 
@@ -190,6 +253,14 @@ impl Config {
         DEFAULT_RPC_ADDR.to_string()
     }
 
+    /// rpc_addr first, it is used until the health of the endpoints is known
+    pub fn rpc_endpoints(&self) -> Vec<String> {
+        std::iter::once(&self.rpc_addr)
+            .chain(&self.rpc_addrs)
+            .cloned()
+            .collect()
+    }
+
     pub fn default_ws_addr() -> String {
         DEFAULT_WS_ADDR.to_string()
     }
@@ -218,6 +289,10 @@ impl Config {
         DEFAULT_GRPC_ADDR.to_string()
     }
 
+    pub const fn default_account_cache_capacity() -> usize {
+        DEFAULT_ACCOUNT_CACHE_CAPACITY
+    }
+
     pub fn get_grpc_sources(&self) -> Vec<GrpcSource> {
         let mut sources: Vec<GrpcSource> = vec![];
 
@@ -1,6 +1,15 @@
+use std::collections::HashSet;
+use std::str::FromStr;
+
 use serde::{Deserialize, Serialize};
 use solana_lite_rpc_core::encoding::BinaryEncoding;
-use solana_sdk::commitment_config::CommitmentLevel;
+use solana_lite_rpc_core::structures::transaction_filter::TransactionFilter;
+use solana_rpc_client_api::response::RpcResponseContext;
+use solana_sdk::commitment_config::{CommitmentConfig, CommitmentLevel};
+use solana_sdk::{pubkey::Pubkey, signature::Signature, slot_history::Slot};
+use solana_transaction_status::{
+    EncodedTransactionWithStatusMeta, TransactionDetails, TransactionStatus, UiTransactionEncoding,
+};
 
 #[derive(Debug, Default, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
@@ -12,12 +21,93 @@ pub struct SendTransactionConfig {
     #[serde(default)]
     pub encoding: BinaryEncoding,
     pub max_retries: Option<u16>,
-    //    pub min_context_slot: Option<Slot>,
+    pub min_context_slot: Option<Slot>,
 }
 
 #[derive(Debug, Default, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct IsBlockHashValidConfig {
     pub commitment: Option<CommitmentLevel>,
-    //    pub minContextSlot: Option<u64>,
+    pub min_context_slot: Option<Slot>,
+}
+
+/// transactionSubscribe filter (SIMD-0069)
+#[derive(Debug, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RpcTransactionSubscribeFilter {
+    pub vote: Option<bool>,
+    pub failed: Option<bool>,
+    pub signature: Option<String>,
+    pub accounts: Option<RpcTransactionSubscribeAccountsFilter>,
+}
+
+#[derive(Debug, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RpcTransactionSubscribeAccountsFilter {
+    #[serde(default)]
+    pub include: Vec<String>,
+    #[serde(default)]
+    pub exclude: Vec<String>,
+    #[serde(default)]
+    pub required: Vec<String>,
+}
+
+impl TryFrom<RpcTransactionSubscribeFilter> for TransactionFilter {
+    type Error = String;
+
+    fn try_from(filter: RpcTransactionSubscribeFilter) -> Result<Self, Self::Error> {
+        let parse_pubkeys = |pubkeys: Vec<String>| {
+            pubkeys
+                .iter()
+                .map(|pubkey| Pubkey::from_str(pubkey))
+                .collect::<Result<HashSet<_>, _>>()
+                .map_err(|_| "Invalid Request: Invalid pubkey provided".to_string())
+        };
+        if let Some(signature) = &filter.signature {
+            Signature::from_str(signature)
+                .map_err(|_| "Invalid Request: Invalid signature provided".to_string())?;
+        }
+        let accounts = filter.accounts.unwrap_or_default();
+
+        Ok(TransactionFilter {
+            vote: filter.vote,
+            failed: filter.failed,
+            signature: filter.signature,
+            account_include: parse_pubkeys(accounts.include)?,
+            account_exclude: parse_pubkeys(accounts.exclude)?,
+            account_required: parse_pubkeys(accounts.required)?,
+        })
+    }
+}
+
+#[derive(Debug, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RpcTransactionSubscribeConfig {
+    #[serde(flatten)]
+    pub commitment: Option<CommitmentConfig>,
+    pub encoding: Option<UiTransactionEncoding>,
+    pub transaction_details: Option<TransactionDetails>,
+    pub show_rewards: Option<bool>,
+    pub max_supported_transaction_version: Option<u8>,
+}
+
+/// transactionSubscribe notification, the transaction is omitted unless its details are requested
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RpcTransactionUpdate {
+    pub signature: String,
+    pub slot: Slot,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub transaction: Option<EncodedTransactionWithStatusMeta>,
+}
+
+/// getSignatureStatuses response; the status of an expired transaction is null like an unknown one,
+/// expiredSignatures lists the transactions sent through lite-rpc whose blockhash expired before they landed
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RpcSignatureStatusesResponse {
+    pub context: RpcResponseContext,
+    pub value: Vec<Option<TransactionStatus>>,
+    #[serde(default)]
+    pub expired_signatures: Vec<String>,
 }
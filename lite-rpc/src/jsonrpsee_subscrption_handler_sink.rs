@@ -1,14 +1,27 @@
+use std::sync::Mutex;
+
 use async_trait::async_trait;
 use jsonrpsee::{SubscriptionMessage, SubscriptionSink};
 use solana_rpc_client_api::response::{Response as RpcResponse, RpcResponseContext};
+use tokio::sync::oneshot;
 
 pub struct JsonRpseeSubscriptionHandlerSink {
     jsonrpsee_sink: SubscriptionSink,
+    // jsonrpsee sends the error notification when the subscription method returns the error
+    close_with_error_sender: Mutex<Option<oneshot::Sender<String>>>,
 }
 
 impl JsonRpseeSubscriptionHandlerSink {
-    pub fn new(jsonrpsee_sink: SubscriptionSink) -> Self {
-        Self { jsonrpsee_sink }
+    /// the receiver yields the error the subscription is closed with; it fails when the sink is dropped
+    pub fn new(jsonrpsee_sink: SubscriptionSink) -> (Self, oneshot::Receiver<String>) {
+        let (close_with_error_sender, close_with_error_receiver) = oneshot::channel();
+        (
+            Self {
+                jsonrpsee_sink,
+                close_with_error_sender: Mutex::new(Some(close_with_error_sender)),
+            },
+            close_with_error_receiver,
+        )
     }
 }
 
@@ -21,10 +34,7 @@ impl solana_lite_rpc_core::traits::subscription_sink::SubscriptionSink
             .jsonrpsee_sink
             .send(
                 SubscriptionMessage::from_json(&RpcResponse {
-                    context: RpcResponseContext {
-                        slot,
-                        api_version: None,
-                    },
+                    context: RpcResponseContext::new(slot),
                     value: message,
                 })
                 .unwrap(),
@@ -32,6 +42,12 @@ impl solana_lite_rpc_core::traits::subscription_sink::SubscriptionSink
             .await;
     }
 
+    fn close_with_error(&self, error: String) {
+        if let Some(sender) = self.close_with_error_sender.lock().unwrap().take() {
+            let _ = sender.send(error);
+        }
+    }
+
     fn is_closed(&self) -> bool {
         self.jsonrpsee_sink.is_closed()
     }
@@ -1,7 +1,7 @@
 pub mod rpc_tester;
 
 use crate::rpc_tester::RpcTester;
-use anyhow::bail;
+use anyhow::{bail, Context};
 use dashmap::DashMap;
 use lite_rpc::bridge::LiteBridge;
 use lite_rpc::cli::Config;
@@ -17,21 +17,29 @@ use solana_lite_rpc_cluster_endpoints::grpc_subscription_autoreconnect::{
 use solana_lite_rpc_cluster_endpoints::json_rpc_leaders_getter::JsonRpcLeaderGetter;
 use solana_lite_rpc_cluster_endpoints::json_rpc_subscription::create_json_rpc_polling_subscription;
 use solana_lite_rpc_cluster_endpoints::rpc_polling::poll_blocks::NUM_PARALLEL_TASKS_DEFAULT;
+use solana_lite_rpc_cluster_endpoints::rpc_pool::RpcEndpointPool;
 use solana_lite_rpc_core::keypair_loader::load_identity_keypair;
 use solana_lite_rpc_core::stores::{
+    account_filter_store::AccountFilterStore,
+    account_store::AccountStore,
     block_information_store::{BlockInformation, BlockInformationStore},
     cluster_info_store::ClusterInfo,
     data_cache::{DataCache, SlotCache},
+    performance_samples_store::PerformanceSamplesStore,
+    program_account_store::ProgramAccountStore,
     subscription_store::SubscriptionStore,
     tx_store::TxStore,
+    vote_account_store::VoteAccountStore,
 };
 use solana_lite_rpc_core::structures::leaderschedule::CalculatedSchedule;
 use solana_lite_rpc_core::structures::{
-    epoch::EpochCache, identity_stakes::IdentityStakes, notifications::NotificationSender,
-    produced_block::ProducedBlock,
+    epoch::EpochCache, fork_tree::ForkTree, identity_stakes::IdentityStakes,
+    notifications::NotificationSender, produced_block::ProducedBlock,
 };
 use solana_lite_rpc_core::types::BlockStream;
 use solana_lite_rpc_core::AnyhowJoinHandle;
+use solana_lite_rpc_history::block_stores::multiple_strategy_block_store::MultipleStrategyBlockStorage;
+use solana_lite_rpc_history::block_stores::postgres_block_store::PostgresBlockStore;
 use solana_lite_rpc_history::history::History;
 use solana_lite_rpc_history::postgres::postgres_config::PostgresSessionConfig;
 use solana_lite_rpc_history::postgres::postgres_session::PostgresSessionCache;
@@ -42,12 +50,16 @@ use solana_lite_rpc_services::tpu_utils::tpu_service::{TpuService, TpuServiceCon
 use solana_lite_rpc_services::transaction_replayer::TransactionReplayer;
 use solana_lite_rpc_services::tx_sender::TxSender;
 
+use solana_account_decoder::parse_token::spl_token_ids;
 use solana_lite_rpc_block_priofees::start_block_priofees_task;
 use solana_rpc_client::nonblocking::rpc_client::RpcClient;
+use solana_rpc_client::rpc_client::RpcClientConfig;
 use solana_sdk::commitment_config::CommitmentConfig;
+use solana_sdk::pubkey::Pubkey;
 use solana_sdk::signature::Keypair;
 use solana_sdk::signer::Signer;
 use std::net::{SocketAddr, ToSocketAddrs};
+use std::str::FromStr;
 use std::sync::Arc;
 use std::time::Duration;
 use tokio::sync::mpsc;
@@ -101,7 +113,12 @@ pub async fn start_postgres(
     Ok((Some(postgres_send), postgres))
 }
 
-pub async fn start_lite_rpc(args: Config, rpc_client: Arc<RpcClient>) -> anyhow::Result<()> {
+pub async fn start_lite_rpc(args: Config, rpc_pool: RpcEndpointPool) -> anyhow::Result<()> {
+    // requests fail over between the upstream rpc endpoints
+    let rpc_client = Arc::new(RpcClient::new_sender(
+        rpc_pool.clone(),
+        RpcClientConfig::with_commitment(CommitmentConfig::default()),
+    ));
     let grpc_sources = args.get_grpc_sources();
     log::info!("grpc_sources:{grpc_sources:?}");
     let Config {
@@ -115,6 +132,15 @@ pub async fn start_lite_rpc(args: Config, rpc_client: Arc<RpcClient>) -> anyhow:
         transaction_retry_after_secs,
         quic_proxy_addr,
         use_grpc,
+        block_store_postgres,
+        cached_accounts,
+        cached_programs,
+        account_cache_capacity,
+        indexed_programs,
+        index_token_accounts,
+        calculate_leader_schedule_form_geyser,
+        grpc_addr,
+        passthrough,
         ..
     } = args;
 
@@ -128,6 +154,45 @@ pub async fn start_lite_rpc(args: Config, rpc_client: Arc<RpcClient>) -> anyhow:
 
     let tpu_connection_path = configure_tpu_connection_path(quic_proxy_addr);
 
+    let account_filters = AccountFilterStore::default();
+    // streamed for the whole lifetime of lite-rpc
+    for account in &cached_accounts {
+        account_filters.subscribe_account(
+            Pubkey::from_str(account).context("Invalid pubkey in cached_accounts")?,
+        );
+    }
+    for program in &cached_programs {
+        account_filters.subscribe_program(
+            Pubkey::from_str(program).context("Invalid pubkey in cached_programs")?,
+        );
+    }
+
+    let program_accounts = ProgramAccountStore::default();
+    let indexed_programs = if use_grpc {
+        let mut indexed_programs = indexed_programs
+            .iter()
+            .map(|program| Pubkey::from_str(program).context("Invalid pubkey in indexed_programs"))
+            .collect::<anyhow::Result<Vec<_>>>()?;
+        // token accounts are only served when both token programs are indexed
+        if index_token_accounts {
+            for program_id in spl_token_ids() {
+                if !indexed_programs.contains(&program_id) {
+                    indexed_programs.push(program_id);
+                }
+            }
+        }
+        indexed_programs
+    } else {
+        if !indexed_programs.is_empty() || index_token_accounts {
+            log::warn!("Indexed programs require grpc, getProgramAccounts and token accounts are served by rpc");
+        }
+        vec![]
+    };
+    for program_id in &indexed_programs {
+        account_filters.subscribe_program(*program_id);
+        program_accounts.add_program(*program_id);
+    }
+
     let (subscriptions, cluster_endpoint_tasks) = if use_grpc {
         info!("Creating geyser subscription...");
 
@@ -145,6 +210,7 @@ pub async fn start_lite_rpc(args: Config, rpc_client: Arc<RpcClient>) -> anyhow:
                     GrpcSourceConfig::new(s.addr.clone(), s.x_token.clone(), None, timeouts.clone())
                 })
                 .collect(),
+            account_filters.watch(),
         )?
 
         // create_grpc_subscription(
@@ -157,11 +223,11 @@ pub async fn start_lite_rpc(args: Config, rpc_client: Arc<RpcClient>) -> anyhow:
         create_json_rpc_polling_subscription(rpc_client.clone(), NUM_PARALLEL_TASKS_DEFAULT)?
     };
     let EndpointStreaming {
-        // note: blocks_notifier will be dropped at some point
         blocks_notifier,
         cluster_info_notifier,
         slot_notifier,
         vote_account_notifier,
+        account_notifier,
     } = subscriptions;
 
     info!("Waiting for first finalized block...");
@@ -185,6 +251,13 @@ pub async fn start_lite_rpc(args: Config, rpc_client: Arc<RpcClient>) -> anyhow:
         },
         epoch_data,
         leader_schedule: Arc::new(RwLock::new(CalculatedSchedule::default())),
+        performance_samples: PerformanceSamplesStore::default(),
+        account_filters: account_filters.clone(),
+        accounts: AccountStore::new(account_cache_capacity, account_filters),
+        program_accounts,
+        vote_accounts: VoteAccountStore::default(),
+        fork_tree: Arc::new(RwLock::new(ForkTree::new(finalized_block.slot))),
+        reorg_notifier: tokio::sync::broadcast::channel(100).0,
     };
 
     let data_cache_service = DataCachingService {
@@ -198,12 +271,49 @@ pub async fn start_lite_rpc(args: Config, rpc_client: Arc<RpcClient>) -> anyhow:
         slot_notifier.resubscribe(),
         cluster_info_notifier,
         vote_account_notifier,
+        account_notifier
+            .as_ref()
+            .map(|notifier| notifier.resubscribe()),
     );
 
+    // the account stream is indexed already, the bootstrap adds the accounts which did not change since
+    let bootstrap_rpc_client = Arc::new(RpcClient::new_sender(
+        rpc_pool.with_timeout(Duration::from_secs(600)),
+        RpcClientConfig::with_commitment(CommitmentConfig::finalized()),
+    ));
+    for program_id in indexed_programs {
+        let program_accounts = data_cache.program_accounts.clone();
+        let bootstrap_rpc_client = bootstrap_rpc_client.clone();
+        tokio::spawn(async move {
+            info!("Bootstrapping accounts of program {program_id}...");
+            match program_accounts
+                .bootstrap_program(&bootstrap_rpc_client, program_id)
+                .await
+            {
+                Ok(()) => info!("Accounts of program {program_id} indexed"),
+                Err(err) => log::error!(
+                    "Failed to bootstrap accounts of program {program_id}, served by rpc: {err:?}"
+                ),
+            }
+        });
+    }
+
     let (_block_priofees_task, block_priofees_service) =
         start_block_priofees_task(blocks_notifier.resubscribe()).await;
 
-    drop(blocks_notifier);
+    let history = match block_store_postgres {
+        Some(block_store_postgres) => {
+            info!("Serving history from postgres block store");
+            let persistent_block_storage =
+                PostgresBlockStore::new(data_cache.epoch_data.clone(), block_store_postgres).await;
+            History::new_with_block_storage(MultipleStrategyBlockStorage::new(
+                persistent_block_storage,
+                None,
+            ))
+        }
+        None => History::new(),
+    };
+    let history_block_listener = history.start_block_listener(blocks_notifier.resubscribe());
 
     let (notification_channel, postgres) = start_postgres(postgres).await?;
 
@@ -248,11 +358,27 @@ pub async fn start_lite_rpc(args: Config, rpc_client: Arc<RpcClient>) -> anyhow:
         slot_notifier.resubscribe(),
     );
 
-    drop(slot_notifier);
-
     let support_service = tokio::spawn(async move { spawner.spawn_support_services().await });
 
-    let history = History::new();
+    // vote accounts are computed from geyser stakes, otherwise served from the polled ones
+    let vote_accounts_sender = if use_grpc && calculate_leader_schedule_form_geyser {
+        let (vote_accounts_sender, vote_accounts_receiver) = mpsc::channel(1000);
+        let stake_vote_jh = solana_lite_rpc_stakevote::start_stakes_and_votes_loop(
+            data_cache.clone(),
+            slot_notifier.resubscribe(),
+            vote_accounts_receiver,
+            bootstrap_rpc_client.clone(),
+            grpc_addr,
+        )
+        .await?;
+        tokio::spawn(async move {
+            let err = stake_vote_jh.await;
+            log::error!("Vote and stake Services exit with error: {err:?}");
+        });
+        Some(vote_accounts_sender)
+    } else {
+        None
+    };
 
     let bridge_service = tokio::spawn(
         LiteBridge::new(
@@ -261,6 +387,11 @@ pub async fn start_lite_rpc(args: Config, rpc_client: Arc<RpcClient>) -> anyhow:
             transaction_service,
             history,
             block_priofees_service,
+            slot_notifier,
+            blocks_notifier,
+            account_notifier,
+            vote_accounts_sender,
+            passthrough,
         )
         .start(lite_rpc_http_addr, lite_rpc_ws_addr),
     );
@@ -281,6 +412,9 @@ pub async fn start_lite_rpc(args: Config, rpc_client: Arc<RpcClient>) -> anyhow:
         res = postgres => {
             anyhow::bail!("Postgres service {res:?}");
         }
+        res = history_block_listener => {
+            anyhow::bail!("History block listener {res:?}");
+        }
         res = futures::future::select_all(data_caching_service) => {
             anyhow::bail!("Data caching service failed {res:?}")
         }
@@ -297,14 +431,14 @@ pub async fn main() -> anyhow::Result<()> {
     let config = Config::load().await?;
 
     let ctrl_c_signal = tokio::signal::ctrl_c();
-    let Config { rpc_addr, .. } = &config;
-    // rpc client
-    let rpc_client = Arc::new(RpcClient::new(rpc_addr.clone()));
-    let rpc_tester = tokio::spawn(RpcTester::new(rpc_client.clone()).start());
-
-    info!("Use RPC address: {}", obfuscate_rpcurl(rpc_addr));
+    let rpc_endpoints = config.rpc_endpoints();
+    for (index, rpc_addr) in rpc_endpoints.iter().enumerate() {
+        info!("Use RPC address #{index}: {}", obfuscate_rpcurl(rpc_addr));
+    }
+    let rpc_pool = RpcEndpointPool::new(&rpc_endpoints)?;
+    let rpc_tester = tokio::spawn(RpcTester::new(rpc_pool.clone()).start());
 
-    let main = start_lite_rpc(config, rpc_client);
+    let main = start_lite_rpc(config, rpc_pool);
 
     tokio::select! {
         err = rpc_tester => {
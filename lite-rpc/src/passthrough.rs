@@ -0,0 +1,151 @@
+use std::collections::HashSet;
+
+/// methods of the solana rpc api which can be forwarded to the upstream rpc
+pub const SOLANA_RPC_METHODS: &[&str] = &[
+    "getAccountInfo",
+    "getBalance",
+    "getBlock",
+    "getBlockCommitment",
+    "getBlockHeight",
+    "getBlockProduction",
+    "getBlockTime",
+    "getBlocks",
+    "getBlocksWithLimit",
+    "getClusterNodes",
+    "getEpochInfo",
+    "getEpochSchedule",
+    "getFeeForMessage",
+    "getFirstAvailableBlock",
+    "getGenesisHash",
+    "getHealth",
+    "getHighestSnapshotSlot",
+    "getIdentity",
+    "getInflationGovernor",
+    "getInflationRate",
+    "getInflationReward",
+    "getLargestAccounts",
+    "getLatestBlockhash",
+    "getLeaderSchedule",
+    "getMaxRetransmitSlot",
+    "getMaxShredInsertSlot",
+    "getMinimumBalanceForRentExemption",
+    "getMultipleAccounts",
+    "getProgramAccounts",
+    "getRecentPerformanceSamples",
+    "getRecentPrioritizationFees",
+    "getSignatureStatuses",
+    "getSignaturesForAddress",
+    "getSlot",
+    "getSlotLeader",
+    "getSlotLeaders",
+    "getStakeActivation",
+    "getStakeMinimumDelegation",
+    "getSupply",
+    "getTokenAccountBalance",
+    "getTokenAccountsByDelegate",
+    "getTokenAccountsByOwner",
+    "getTokenLargestAccounts",
+    "getTokenSupply",
+    "getTransaction",
+    "getTransactionCount",
+    "getVersion",
+    "getVoteAccounts",
+    "isBlockhashValid",
+    "minimumLedgerSlot",
+    "requestAirdrop",
+    "sendTransaction",
+    "simulateTransaction",
+    // deprecated
+    "getConfirmedBlock",
+    "getConfirmedBlocks",
+    "getConfirmedBlocksWithLimit",
+    "getConfirmedSignaturesForAddress2",
+    "getConfirmedTransaction",
+    "getFeeCalculatorForBlockhash",
+    "getFeeRateGovernor",
+    "getFees",
+    "getRecentBlockhash",
+    "getSnapshotSlot",
+];
+
+/// methods lite-rpc does not implement are forwarded to the upstream rpc
+#[derive(Debug, Clone, Default, serde::Deserialize)]
+pub struct PassthroughConfig {
+    #[serde(default)]
+    pub enabled: bool,
+    /// only these methods are forwarded, every solana rpc method if empty
+    /// methods unknown to the solana rpc api (e.g. provider extensions) have to be listed here
+    #[serde(default)]
+    pub allowed_methods: Vec<String>,
+    /// never forwarded
+    #[serde(default)]
+    pub denied_methods: Vec<String>,
+}
+
+impl PassthroughConfig {
+    /// the methods to forward, leaving out the ones implemented by lite-rpc
+    pub fn forwarded_methods(&self, implemented_methods: &HashSet<&str>) -> Vec<&'static str> {
+        if !self.enabled {
+            return vec![];
+        }
+
+        let candidates: Vec<&'static str> = if self.allowed_methods.is_empty() {
+            SOLANA_RPC_METHODS.to_vec()
+        } else {
+            self.allowed_methods
+                .iter()
+                .map(|method| {
+                    SOLANA_RPC_METHODS
+                        .iter()
+                        .find(|known| *known == method)
+                        .copied()
+                        // registered once for the lifetime of the server
+                        .unwrap_or_else(|| Box::leak(method.clone().into_boxed_str()))
+                })
+                .collect()
+        };
+
+        candidates
+            .into_iter()
+            .filter(|method| !implemented_methods.contains(method))
+            .filter(|method| !self.denied_methods.iter().any(|denied| denied == method))
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn select_forwarded_methods() {
+        let implemented_methods = HashSet::from(["getSlot", "sendTransaction"]);
+
+        assert!(PassthroughConfig::default()
+            .forwarded_methods(&implemented_methods)
+            .is_empty());
+
+        let forwarded = PassthroughConfig {
+            enabled: true,
+            allowed_methods: vec![],
+            denied_methods: vec!["requestAirdrop".to_string()],
+        }
+        .forwarded_methods(&implemented_methods);
+        assert!(forwarded.contains(&"simulateTransaction"));
+        assert!(!forwarded.contains(&"getSlot"));
+        assert!(!forwarded.contains(&"requestAirdrop"));
+        assert_eq!(forwarded.len(), SOLANA_RPC_METHODS.len() - 3);
+
+        let forwarded = PassthroughConfig {
+            enabled: true,
+            allowed_methods: vec![
+                "getFeeForMessage".to_string(),
+                "getSlot".to_string(),
+                "getAsset".to_string(),
+            ],
+            denied_methods: vec![],
+        }
+        .forwarded_methods(&implemented_methods);
+        assert_eq!(forwarded, vec!["getFeeForMessage", "getAsset"]);
+    }
+}
@@ -1,22 +1,31 @@
-use crate::configs::{IsBlockHashValidConfig, SendTransactionConfig};
+use crate::configs::{
+    IsBlockHashValidConfig, RpcSignatureStatusesResponse, RpcTransactionSubscribeConfig,
+    RpcTransactionSubscribeFilter, RpcTransactionUpdate, SendTransactionConfig,
+};
 use jsonrpsee::core::SubscriptionResult;
 use jsonrpsee::proc_macros::rpc;
+use solana_account_decoder::parse_token::UiTokenAmount;
+use solana_account_decoder::UiAccount;
 use solana_lite_rpc_block_priofees::rpc_data::PrioFeesStats;
+use solana_lite_rpc_core::stores::cluster_info_store::ClusterVersionCount;
 use solana_rpc_client_api::config::{
-    RpcBlockSubscribeConfig, RpcBlockSubscribeFilter, RpcBlocksConfigWrapper, RpcContextConfig,
-    RpcGetVoteAccountsConfig, RpcLeaderScheduleConfig, RpcProgramAccountsConfig,
-    RpcRequestAirdropConfig, RpcSignatureStatusConfig, RpcSignatureSubscribeConfig,
-    RpcSignaturesForAddressConfig, RpcTransactionLogsConfig, RpcTransactionLogsFilter,
+    RpcAccountInfoConfig, RpcBlockConfig, RpcBlockSubscribeConfig, RpcBlockSubscribeFilter,
+    RpcBlocksConfigWrapper, RpcContextConfig, RpcEncodingConfigWrapper, RpcGetVoteAccountsConfig,
+    RpcLeaderScheduleConfig, RpcProgramAccountsConfig, RpcRequestAirdropConfig,
+    RpcSignatureStatusConfig, RpcSignatureSubscribeConfig, RpcSignaturesForAddressConfig,
+    RpcTokenAccountsFilter, RpcTransactionConfig, RpcTransactionLogsConfig,
+    RpcTransactionLogsFilter,
 };
 use solana_rpc_client_api::response::{
-    Response as RpcResponse, RpcBlockhash, RpcConfirmedTransactionStatusWithSignature,
-    RpcContactInfo, RpcPerfSample, RpcPrioritizationFee, RpcVersionInfo, RpcVoteAccountStatus,
+    OptionalContext, Response as RpcResponse, RpcBlockhash,
+    RpcConfirmedTransactionStatusWithSignature, RpcContactInfo, RpcKeyedAccount, RpcPerfSample,
+    RpcPrioritizationFee, RpcTokenAccountBalance, RpcVersionInfo, RpcVoteAccountStatus,
 };
 use solana_sdk::commitment_config::CommitmentConfig;
 use solana_sdk::epoch_info::EpochInfo;
 use solana_sdk::pubkey::Pubkey;
 use solana_sdk::slot_history::Slot;
-use solana_transaction_status::{TransactionStatus, UiConfirmedBlock};
+use solana_transaction_status::UiConfirmedBlock;
 use std::collections::HashMap;
 
 pub type Result<T> = std::result::Result<T, jsonrpsee::core::Error>;
@@ -28,7 +37,11 @@ pub trait LiteRpc {
     // ***********************
 
     #[method(name = "getBlock")]
-    async fn get_block(&self, slot: u64) -> Result<Option<UiConfirmedBlock>>;
+    async fn get_block(
+        &self,
+        slot: u64,
+        config: Option<RpcEncodingConfigWrapper<RpcBlockConfig>>,
+    ) -> Result<Option<UiConfirmedBlock>>;
 
     #[method(name = "getBlocks")]
     async fn get_blocks(
@@ -38,6 +51,14 @@ pub trait LiteRpc {
         commitment: Option<CommitmentConfig>,
     ) -> Result<Vec<Slot>>;
 
+    #[method(name = "getBlocksWithLimit")]
+    async fn get_blocks_with_limit(
+        &self,
+        start_slot: Slot,
+        limit: usize,
+        commitment: Option<CommitmentConfig>,
+    ) -> Result<Vec<Slot>>;
+
     #[method(name = "getSignaturesForAddress")]
     async fn get_signatures_for_address(
         &self,
@@ -45,14 +66,14 @@ pub trait LiteRpc {
         config: Option<RpcSignaturesForAddressConfig>,
     ) -> Result<Vec<RpcConfirmedTransactionStatusWithSignature>>;
 
-    // issue:  solana_transaction_status::EncodedConfirmedTransactionWithStatusMeta does not implement Clone
-    //
-    //#[method(name = "getTransaction")]
-    //async fn get_transaction(
-    //    &self,
-    //    signature_str: String,
-    //    config: Option<RpcEncodingConfigWrapper<RpcTransactionConfig>>,
-    //) -> Result<Option<EncodedConfirmedTransactionWithStatusMeta>>;
+    // solana_transaction_status::EncodedConfirmedTransactionWithStatusMeta does not implement Clone
+    // which jsonrpsee requires - the encoded transaction is returned as json value instead
+    #[method(name = "getTransaction")]
+    async fn get_transaction(
+        &self,
+        signature_str: String,
+        config: Option<RpcEncodingConfigWrapper<RpcTransactionConfig>>,
+    ) -> Result<Option<serde_json::Value>>;
 
     // ***********************
     // Cluster Domain
@@ -117,7 +138,7 @@ pub trait LiteRpc {
         &self,
         signature_strs: Vec<String>,
         config: Option<RpcSignatureStatusConfig>,
-    ) -> Result<RpcResponse<Vec<Option<TransactionStatus>>>>;
+    ) -> Result<RpcSignatureStatusesResponse>;
 
     #[method(name = "getRecentPrioritizationFees")]
     async fn get_recent_prioritization_fees(
@@ -136,6 +157,68 @@ pub trait LiteRpc {
         send_transaction_config: Option<SendTransactionConfig>,
     ) -> Result<String>;
 
+    // ***********************
+    // Account Domain
+    // ***********************
+
+    #[method(name = "getAccountInfo")]
+    async fn get_account_info(
+        &self,
+        pubkey_str: String,
+        config: Option<RpcAccountInfoConfig>,
+    ) -> Result<RpcResponse<Option<UiAccount>>>;
+
+    #[method(name = "getMultipleAccounts")]
+    async fn get_multiple_accounts(
+        &self,
+        pubkey_strs: Vec<String>,
+        config: Option<RpcAccountInfoConfig>,
+    ) -> Result<RpcResponse<Vec<Option<UiAccount>>>>;
+
+    #[method(name = "getProgramAccounts")]
+    async fn get_program_accounts(
+        &self,
+        program_id_str: String,
+        config: Option<RpcProgramAccountsConfig>,
+    ) -> Result<OptionalContext<Vec<RpcKeyedAccount>>>;
+
+    #[method(name = "getTokenAccountsByOwner")]
+    async fn get_token_accounts_by_owner(
+        &self,
+        owner_str: String,
+        filter: RpcTokenAccountsFilter,
+        config: Option<RpcAccountInfoConfig>,
+    ) -> Result<RpcResponse<Vec<RpcKeyedAccount>>>;
+
+    #[method(name = "getTokenAccountsByDelegate")]
+    async fn get_token_accounts_by_delegate(
+        &self,
+        delegate_str: String,
+        filter: RpcTokenAccountsFilter,
+        config: Option<RpcAccountInfoConfig>,
+    ) -> Result<RpcResponse<Vec<RpcKeyedAccount>>>;
+
+    #[method(name = "getTokenAccountBalance")]
+    async fn get_token_account_balance(
+        &self,
+        pubkey_str: String,
+        commitment: Option<CommitmentConfig>,
+    ) -> Result<RpcResponse<UiTokenAmount>>;
+
+    #[method(name = "getTokenLargestAccounts")]
+    async fn get_token_largest_accounts(
+        &self,
+        mint_str: String,
+        commitment: Option<CommitmentConfig>,
+    ) -> Result<RpcResponse<Vec<RpcTokenAccountBalance>>>;
+
+    #[method(name = "getBalance")]
+    async fn get_balance(
+        &self,
+        pubkey_str: String,
+        config: Option<RpcContextConfig>,
+    ) -> Result<RpcResponse<u64>>;
+
     // ***********************
     // Deprecated
     // ***********************
@@ -155,17 +238,24 @@ pub trait LiteRpc {
     // Direct Subscription Domain
     // ***********************
 
-    #[subscription(name = "programSubscribe" => "programNotification", unsubscribe="programUnsubscribe", item=RpcResponse<serde_json::Value>)]
+    #[subscription(name = "programSubscribe" => "programNotification", unsubscribe="programUnsubscribe", item=RpcResponse<RpcKeyedAccount>)]
     async fn program_subscribe(
         &self,
         pubkey_str: String,
         config: Option<RpcProgramAccountsConfig>,
     ) -> SubscriptionResult;
 
-    #[subscription(name = "slotSubscribe" => "slotNotification", unsubscribe="slotUnsubscribe", item=Slot)]
+    #[subscription(name = "accountSubscribe" => "accountNotification", unsubscribe="accountUnsubscribe", item=RpcResponse<UiAccount>)]
+    async fn account_subscribe(
+        &self,
+        pubkey_str: String,
+        config: Option<RpcAccountInfoConfig>,
+    ) -> SubscriptionResult;
+
+    #[subscription(name = "slotSubscribe" => "slotNotification", unsubscribe="slotUnsubscribe", item=SlotInfo)]
     async fn slot_subscribe(&self) -> SubscriptionResult;
 
-    #[subscription(name = "blockSubscribe" => "blockNotification", unsubscribe="blockUnsubscribe", item=RpcResponse<UiConfirmedBlock>)]
+    #[subscription(name = "blockSubscribe" => "blockNotification", unsubscribe="blockUnsubscribe", item=RpcResponse<RpcBlockUpdate>)]
     async fn block_subscribe(
         &self,
         filter: RpcBlockSubscribeFilter,
@@ -174,11 +264,12 @@ pub trait LiteRpc {
 
     // [transactionSubscribe](https://github.com/solana-foundation/solana-improvement-documents/pull/69)
     //
-    //#[subscription(name = "transactionSubscribe" => "transactionNotification", unsubscribe="transactionUnsubscribe", item=RpcResponse<RpcConfirmedTransactionStatusWithSignature>)]
-    //async fn transaction_subscribe(
-    //    &self,
-    //    commitment_config: CommitmentConfig,
-    //) -> SubscriptionResult;
+    #[subscription(name = "transactionSubscribe" => "transactionNotification", unsubscribe="transactionUnsubscribe", item=RpcResponse<RpcTransactionUpdate>)]
+    async fn transaction_subscribe(
+        &self,
+        filter: RpcTransactionSubscribeFilter,
+        config: Option<RpcTransactionSubscribeConfig>,
+    ) -> SubscriptionResult;
 
     // ***********************
     // Indirect Subscription Domain
@@ -191,7 +282,6 @@ pub trait LiteRpc {
         config: Option<RpcTransactionLogsConfig>,
     ) -> SubscriptionResult;
 
-    // WARN: enable_received_notification: bool is ignored
     #[subscription(name = "signatureSubscribe" => "signatureNotification", unsubscribe="signatureUnsubscribe", item=RpcResponse<serde_json::Value>)]
     async fn signature_subscribe(
         &self,
@@ -242,4 +332,12 @@ pub trait LiteRpc {
     /// subscribe to prio fees distribution per block; uses confirmation level "confirmed"
     #[subscription(name = "blockPrioritizationFeesSubscribe" => "blockPrioritizationFeesNotification", unsubscribe="blockPrioritizationFeesUnsubscribe", item=PrioFeesStats)]
     async fn latest_block_priofees_subscribe(&self) -> SubscriptionResult;
+
+    // ***********************
+    // node count per version and feature set to follow validator upgrade rollouts
+    // (this is special method not available in solana rpc)
+    // ***********************
+
+    #[method(name = "getClusterVersionSummary")]
+    async fn get_cluster_version_summary(&self) -> crate::rpc::Result<Vec<ClusterVersionCount>>;
 }
@@ -1,41 +1,54 @@
-use anyhow::bail;
-use prometheus::{opts, register_gauge, Gauge};
-use solana_rpc_client::nonblocking::rpc_client::RpcClient;
-use std::sync::Arc;
+use prometheus::{opts, register_gauge, register_gauge_vec, Gauge, GaugeVec};
+use solana_lite_rpc_cluster_endpoints::rpc_pool::{EndpointHealth, RpcEndpointPool};
 
 lazy_static::lazy_static! {
     static ref RPC_RESPONDING: Gauge =
     register_gauge!(opts!("literpc_rpc_responding", "If RPC is responding")).unwrap();
+    static ref RPC_ENDPOINT_DEGRADED: GaugeVec =
+    register_gauge_vec!(opts!("literpc_rpc_endpoint_degraded", "If the RPC endpoint is degraded"), &["endpoint"]).unwrap();
+    static ref RPC_ENDPOINT_SLOT_LAG: GaugeVec =
+    register_gauge_vec!(opts!("literpc_rpc_endpoint_slot_lag", "Slots the RPC endpoint is behind the most advanced one"), &["endpoint"]).unwrap();
 }
 
 pub struct RpcTester {
-    rpc_client: Arc<RpcClient>,
+    rpc_pool: RpcEndpointPool,
 }
 
 impl RpcTester {
-    pub fn new(rpc_client: Arc<RpcClient>) -> Self {
-        Self { rpc_client }
+    pub fn new(rpc_pool: RpcEndpointPool) -> Self {
+        Self { rpc_pool }
     }
 }
 
 impl RpcTester {
-    /// Starts a loop that checks if the rpc is responding every 5 seconds
+    /// Starts a loop that checks the health of the rpc endpoints every 5 seconds
+    /// degraded endpoints are only used when no healthy one is left
     pub async fn start(self) -> anyhow::Result<()> {
-        let mut error_counter = 0;
-        let rpc_client = self.rpc_client;
+        let rpc_pool = self.rpc_pool;
         loop {
             // sleep for 5 seconds
             tokio::time::sleep(tokio::time::Duration::from_secs(5)).await;
-            // do a simple request to self for getVersion
-            let Err(err) = rpc_client.get_slot().await else {
-                RPC_RESPONDING.set(1.0);
-                continue;
-            };
+            rpc_pool.check_health().await;
 
-            RPC_RESPONDING.set(0.0);
-            error_counter += 1;
-            if error_counter > 10 {
-                bail!("RPC seems down restarting service error {err:?}");
+            let health = rpc_pool.health();
+            for (index, endpoint_health) in health.iter().enumerate() {
+                let endpoint = index.to_string();
+                RPC_ENDPOINT_DEGRADED
+                    .with_label_values(&[endpoint.as_str()])
+                    .set(if endpoint_health.is_degraded() {
+                        1.0
+                    } else {
+                        0.0
+                    });
+                RPC_ENDPOINT_SLOT_LAG
+                    .with_label_values(&[endpoint.as_str()])
+                    .set(endpoint_health.slot_lag as f64);
+            }
+            if health.iter().all(EndpointHealth::is_degraded) {
+                log::error!("All RPC endpoints are degraded");
+                RPC_RESPONDING.set(0.0);
+            } else {
+                RPC_RESPONDING.set(1.0);
             }
         }
     }
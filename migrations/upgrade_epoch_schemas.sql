@@ -0,0 +1,159 @@
+-- brings the epoch schemas (rpc2a_epoch_*) written by older lite-rpc versions up to date with the
+-- tables lite-rpc creates for new epochs; every step can be run again
+-- the steps only change schemas which exist when the script runs, new epoch schemas are created up to date
+
+-- YOU NEED TO run this once before upgrading if block_store_postgres is configured
+
+-- transactions: status meta
+-- older transactions have no meta, they get no fee, no balances and no loaded addresses ('')
+DO $$
+DECLARE
+    epoch_schema TEXT;
+BEGIN
+    FOR epoch_schema IN
+        SELECT table_schema FROM information_schema.tables
+        WHERE table_schema LIKE 'rpc2a\_epoch\_%' AND table_name = 'transactions'
+    LOOP
+        EXECUTE format(
+            'ALTER TABLE %I.transactions
+                ADD COLUMN IF NOT EXISTS fee BIGINT NOT NULL DEFAULT 0,
+                ADD COLUMN IF NOT EXISTS pre_balances BIGINT[] NOT NULL DEFAULT ''{}'',
+                ADD COLUMN IF NOT EXISTS post_balances BIGINT[] NOT NULL DEFAULT ''{}'',
+                ADD COLUMN IF NOT EXISTS inner_instructions TEXT,
+                ADD COLUMN IF NOT EXISTS log_messages TEXT[],
+                ADD COLUMN IF NOT EXISTS loaded_addresses TEXT NOT NULL DEFAULT '''',
+                ADD COLUMN IF NOT EXISTS return_data TEXT',
+            epoch_schema);
+    END LOOP;
+END $$;
+
+-- transactions: every signature of the transaction
+-- older transactions only have their first signature (signature), their signatures stay NULL
+DO $$
+DECLARE
+    epoch_schema TEXT;
+BEGIN
+    FOR epoch_schema IN
+        SELECT table_schema FROM information_schema.tables
+        WHERE table_schema LIKE 'rpc2a\_epoch\_%' AND table_name = 'transactions'
+    LOOP
+        EXECUTE format('ALTER TABLE %I.transactions ADD COLUMN IF NOT EXISTS signatures TEXT[]', epoch_schema);
+    END LOOP;
+END $$;
+
+-- transactions: position of the transaction in its block
+-- the position of older transactions is unknown, they are numbered in the order postgres stores them
+DO $$
+DECLARE
+    epoch_schema TEXT;
+BEGIN
+    FOR epoch_schema IN
+        SELECT table_schema FROM information_schema.tables
+        WHERE table_schema LIKE 'rpc2a\_epoch\_%' AND table_name = 'transactions'
+    LOOP
+        EXECUTE format('ALTER TABLE %I.transactions ADD COLUMN IF NOT EXISTS idx_in_block INT', epoch_schema);
+        EXECUTE format(
+            'UPDATE %1$I.transactions tx SET idx_in_block = numbered.idx_in_block
+             FROM (
+                SELECT signature, row_number() OVER (PARTITION BY slot ORDER BY ctid) - 1 AS idx_in_block
+                FROM %1$I.transactions
+             ) numbered
+             WHERE tx.signature = numbered.signature AND tx.idx_in_block IS NULL',
+            epoch_schema);
+        EXECUTE format('ALTER TABLE %I.transactions ALTER COLUMN idx_in_block SET NOT NULL', epoch_schema);
+    END LOOP;
+END $$;
+
+-- account_transactions: index of the accounts used by the transactions, filled from the transactions
+-- the keys are decoded from the stored bincode (base64) of the message and of the loaded addresses
+CREATE OR REPLACE FUNCTION pg_temp.base58_encode(bytes BYTEA) RETURNS TEXT AS $$
+DECLARE
+    alphabet CONSTANT TEXT := '123456789ABCDEFGHJKLMNPQRSTUVWXYZabcdefghijkmnopqrstuvwxyz';
+    num NUMERIC := 0;
+    encoded TEXT := '';
+    i INT;
+BEGIN
+    FOR i IN 0 .. length(bytes) - 1 LOOP
+        num := num * 256 + get_byte(bytes, i);
+    END LOOP;
+    WHILE num > 0 LOOP
+        encoded := substr(alphabet, (num % 58)::INT + 1, 1) || encoded;
+        num := div(num, 58);
+    END LOOP;
+    -- leading zero bytes
+    i := 0;
+    WHILE i < length(bytes) AND get_byte(bytes, i) = 0 LOOP
+        encoded := '1' || encoded;
+        i := i + 1;
+    END LOOP;
+    RETURN encoded;
+END $$ LANGUAGE plpgsql IMMUTABLE;
+
+-- static account keys of the message followed by the keys loaded from address lookup tables
+CREATE OR REPLACE FUNCTION pg_temp.transaction_account_keys(message TEXT, loaded_addresses TEXT) RETURNS SETOF TEXT AS $$
+DECLARE
+    bytes BYTEA := decode(message, 'base64');
+    pos INT := 0;
+    key_count INT := 0;
+    shift INT := 0;
+    b INT;
+    i INT;
+BEGIN
+    -- versioned messages start with 0x80 + version, legacy ones with the header
+    IF get_byte(bytes, 0) >= 128 THEN
+        pos := 1;
+    END IF;
+    pos := pos + 3;
+    -- compact-u16 number of static keys
+    LOOP
+        b := get_byte(bytes, pos);
+        pos := pos + 1;
+        key_count := key_count | ((b & 127) << shift);
+        EXIT WHEN b < 128;
+        shift := shift + 7;
+    END LOOP;
+    FOR i IN 0 .. key_count - 1 LOOP
+        RETURN NEXT pg_temp.base58_encode(substring(bytes FROM pos + i * 32 + 1 FOR 32));
+    END LOOP;
+
+    -- writable then readonly keys, each list prefixed by its u64 little endian length; empty without meta
+    bytes := decode(loaded_addresses, 'base64');
+    pos := 0;
+    WHILE pos + 8 <= length(bytes) LOOP
+        key_count := get_byte(bytes, pos) | (get_byte(bytes, pos + 1) << 8);
+        pos := pos + 8;
+        FOR i IN 0 .. key_count - 1 LOOP
+            RETURN NEXT pg_temp.base58_encode(substring(bytes FROM pos + i * 32 + 1 FOR 32));
+        END LOOP;
+        pos := pos + key_count * 32;
+    END LOOP;
+END $$ LANGUAGE plpgsql IMMUTABLE;
+
+DO $$
+DECLARE
+    epoch_schema TEXT;
+BEGIN
+    FOR epoch_schema IN
+        SELECT table_schema FROM information_schema.tables
+        WHERE table_schema LIKE 'rpc2a\_epoch\_%' AND table_name = 'transactions'
+    LOOP
+        EXECUTE format(
+            'CREATE TABLE IF NOT EXISTS %I.account_transactions (
+                account_key VARCHAR(44) NOT NULL,
+                signature VARCHAR(88) NOT NULL,
+                slot BIGINT NOT NULL,
+                idx_in_block INT NOT NULL,
+                CONSTRAINT pk_account_transactions PRIMARY KEY(account_key, slot, idx_in_block)
+             ) WITH (FILLFACTOR=90)',
+            epoch_schema);
+        EXECUTE format('GRANT ALL ON %I.account_transactions TO r_literpc', epoch_schema);
+        -- transactions indexed already are skipped
+        EXECUTE format(
+            'INSERT INTO %1$I.account_transactions (account_key, signature, slot, idx_in_block)
+             SELECT DISTINCT keys.account_key, tx.signature, tx.slot, tx.idx_in_block
+             FROM %1$I.transactions tx,
+                pg_temp.transaction_account_keys(tx.message, tx.loaded_addresses) AS keys(account_key)
+             ON CONFLICT DO NOTHING',
+            epoch_schema);
+    END LOOP;
+END $$;
@@ -2,15 +2,25 @@ use std::time::Duration;
 
 use anyhow::{bail, Context};
 use prometheus::core::GenericGauge;
-use prometheus::{opts, register_int_counter, register_int_gauge, IntCounter};
+use prometheus::{
+    histogram_opts, opts, register_histogram, register_int_counter, register_int_gauge, Histogram,
+    IntCounter,
+};
 use solana_lite_rpc_core::stores::{
     block_information_store::BlockInformation, data_cache::DataCache,
+    performance_samples_store::PERFORMANCE_SAMPLE_PERIOD_SECS,
+};
+use solana_lite_rpc_core::structures::account_data::AccountNotificationMessage;
+use solana_lite_rpc_core::structures::fork_tree::{ForkTreeNode, ReorgNotification};
+use solana_lite_rpc_core::types::{
+    AccountStream, BlockStream, ClusterInfoStream, SlotStream, VoteAccountStream,
 };
-use solana_lite_rpc_core::types::{BlockStream, ClusterInfoStream, SlotStream, VoteAccountStream};
 use solana_lite_rpc_core::AnyhowJoinHandle;
+use solana_sdk::account::ReadableAccount;
 use solana_sdk::clock::MAX_RECENT_BLOCKHASHES;
 use solana_sdk::commitment_config::CommitmentLevel;
 use solana_transaction_status::{TransactionConfirmationStatus, TransactionStatus};
+use tokio::sync::broadcast::error::RecvError;
 
 lazy_static::lazy_static! {
     static ref NB_CLUSTER_NODES: GenericGauge<prometheus::core::AtomicI64> =
@@ -30,6 +40,19 @@ lazy_static::lazy_static! {
 
     static ref TXS_PROCESSED: IntCounter =
     register_int_counter!(opts!("literpc_txs_processed", "Number of Transactions Processed")).unwrap();
+
+    static ref REORGS: IntCounter =
+    register_int_counter!(opts!("literpc_reorgs", "Number of times abandoned forks were pruned")).unwrap();
+
+    static ref REORG_DEPTH: Histogram = register_histogram!(histogram_opts!(
+        "literpc_reorg_depth",
+        "Number of blocks on the longest abandoned fork",
+        vec![1.0, 2.0, 4.0, 8.0, 16.0, 32.0, 64.0]
+    ))
+    .unwrap();
+
+    static ref TXS_ROLLED_BACK: IntCounter =
+    register_int_counter!(opts!("literpc_txs_rolled_back", "Number of Transactions only seen on abandoned forks")).unwrap();
 }
 
 pub struct DataCachingService {
@@ -44,6 +67,7 @@ impl DataCachingService {
         slot_notification: SlotStream,
         cluster_info_notification: ClusterInfoStream,
         va_notification: VoteAccountStream,
+        account_notification: Option<AccountStream>,
     ) -> Vec<AnyhowJoinHandle> {
         // clone the ledger to move into the processor task
         let data_cache = self.data_cache.clone();
@@ -57,6 +81,18 @@ impl DataCachingService {
                     .block_information_store
                     .add_block(BlockInformation::from_block(&block))
                     .await;
+                data_cache.performance_samples.add_block(&block);
+
+                let reorg = data_cache
+                    .fork_tree
+                    .write()
+                    .await
+                    .add_block(ForkTreeNode::from_block(&block));
+                if let Some(reorg) = reorg {
+                    Self::rollback_orphaned_blocks(&data_cache, &reorg).await;
+                    // nobody might be listening
+                    let _ = data_cache.reorg_notifier.send(reorg);
+                }
 
                 let confirmation_status = match block.commitment_config.commitment {
                     CommitmentLevel::Finalized => TransactionConfirmationStatus::Finalized,
@@ -104,6 +140,13 @@ impl DataCachingService {
                         .notify(block.slot, &tx, block.commitment_config)
                         .await;
                 }
+
+                if block.commitment_config.is_finalized() {
+                    data_cache
+                        .tx_subs
+                        .notify_expired(block.slot, block.block_height, &data_cache.txs)
+                        .await;
+                }
             }
         });
 
@@ -146,8 +189,49 @@ impl DataCachingService {
                     .context("Could not get vote accounts")?;
                 data_cache
                     .identity_stakes
-                    .update_stakes_for_identity(vote_accounts)
+                    .update_stakes_for_identity(vote_accounts.clone())
                     .await;
+                data_cache.vote_accounts.update(vote_accounts).await;
+            }
+        });
+
+        // accounts are only streamed with grpc
+        let account_cache_jh = account_notification.map(|mut account_notification| {
+            let data_cache: DataCache = self.data_cache.clone();
+            let jh: AnyhowJoinHandle = tokio::spawn(async move {
+                loop {
+                    match account_notification.recv().await {
+                        Ok(AccountNotificationMessage { data, commitment }) => {
+                            // accounts of indexed programs are not cached twice
+                            if !data_cache.program_accounts.is_indexed(data.account.owner()) {
+                                data_cache.accounts.update_account(data.clone(), commitment);
+                            }
+                            data_cache.program_accounts.update_account(data, commitment);
+                        }
+                        Err(RecvError::Lagged(lagged)) => {
+                            // the missed accounts are updated again with their next change
+                            log::warn!("Account cache lagged {lagged} account updates");
+                        }
+                        Err(RecvError::Closed) => {
+                            bail!("Account stream closed unexpectedly");
+                        }
+                    }
+                }
+            });
+            jh
+        });
+
+        let data_cache: DataCache = self.data_cache.clone();
+        let performance_sampler_jh = tokio::spawn(async move {
+            let mut interval =
+                tokio::time::interval(Duration::from_secs(PERFORMANCE_SAMPLE_PERIOD_SECS as u64));
+            // first tick completes immediately
+            interval.tick().await;
+            loop {
+                interval.tick().await;
+                data_cache
+                    .performance_samples
+                    .take_sample(PERFORMANCE_SAMPLE_PERIOD_SECS);
             }
         });
 
@@ -160,12 +244,42 @@ impl DataCachingService {
                 data_cache.clean(clean_ttl).await;
             }
         });
-        vec![
+        let mut tasks = vec![
             slot_cache_jh,
             block_cache_jh,
             cluster_info_jh,
             identity_stakes_jh,
+            performance_sampler_jh,
             cleaning_service,
-        ]
+        ];
+        tasks.extend(account_cache_jh);
+        tasks
+    }
+
+    async fn rollback_orphaned_blocks(data_cache: &DataCache, reorg: &ReorgNotification) {
+        REORGS.inc();
+        REORG_DEPTH.observe(reorg.depth as f64);
+        log::warn!(
+            "Pruned {} blocks of abandoned forks at slot {} ({:?}), depth {}",
+            reorg.orphaned_blocks.len(),
+            reorg.slot,
+            reorg.commitment_config.commitment,
+            reorg.depth
+        );
+
+        data_cache
+            .block_information_store
+            .remove_orphaned_blocks(&reorg.orphaned_blocks)
+            .await;
+        for orphaned_block in &reorg.orphaned_blocks {
+            for signature in &orphaned_block.signatures {
+                if data_cache
+                    .txs
+                    .rollback_status(signature, orphaned_block.slot)
+                {
+                    TXS_ROLLED_BACK.inc();
+                }
+            }
+        }
     }
 }
@@ -76,6 +76,7 @@ impl TxSender {
         let forwarded_local_time = Utc::now();
 
         let mut quic_responses = vec![];
+        let mut received_signatures = vec![];
         for transaction_info in transaction_infos.iter() {
             trace!("sending transaction {}", transaction_info.signature);
             txs_sent.insert(
@@ -90,6 +91,7 @@ impl TxSender {
             let quic_response = match tpu_client.send_transaction(transaction_info) {
                 Ok(_) => {
                     TXS_SENT.inc_by(1);
+                    received_signatures.push(transaction_info.signature.clone());
                     1
                 }
                 Err(err) => {
@@ -100,6 +102,15 @@ impl TxSender {
             };
             quic_responses.push(quic_response);
         }
+        if !received_signatures.is_empty() {
+            // slow subscribers must not hold back forwarding
+            let tx_subs = self.data_cache.tx_subs.clone();
+            tokio::spawn(async move {
+                for signature in received_signatures {
+                    tx_subs.notify_received(forwarded_slot, &signature).await;
+                }
+            });
+        }
         if let Some(notifier) = &notifier {
             let notification_msgs = transaction_infos
                 .iter()
@@ -2,7 +2,7 @@ use anyhow::bail;
 use borsh::BorshDeserialize;
 use solana_sdk::pubkey::Pubkey;
 use solana_sdk::stake::state::Delegation;
-use solana_sdk::stake::state::StakeState;
+use solana_sdk::stake::state::StakeStateV2;
 use solana_sdk::stake_history::StakeHistory;
 use solana_sdk::vote::state::VoteState;
 use yellowstone_grpc_proto::prelude::SubscribeUpdateAccount;
@@ -87,10 +87,10 @@ pub fn read_stake_from_account_data(mut data: &[u8]) -> anyhow::Result<Option<De
         bail!("Error: read Stake account with empty data");
     }
     match BorshDeserialize::deserialize(&mut data)? {
-        StakeState::Stake(_, stake) => Ok(Some(stake.delegation)),
-        StakeState::Initialized(_) => Ok(None),
-        StakeState::Uninitialized => Ok(None),
-        StakeState::RewardsPool => Ok(None),
+        StakeStateV2::Stake(_, stake, _) => Ok(Some(stake.delegation)),
+        StakeStateV2::Initialized(_) => Ok(None),
+        StakeStateV2::Uninitialized => Ok(None),
+        StakeStateV2::RewardsPool => Ok(None),
     }
 }
 
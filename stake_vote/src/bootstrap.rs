@@ -7,23 +7,18 @@ use crate::vote::EpochVoteStakes;
 use crate::vote::EpochVoteStakesCache;
 use crate::vote::VoteMap;
 use crate::vote::VoteStore;
-use anyhow::bail;
+use anyhow::{anyhow, bail};
 use futures::future::join_all;
 use futures_util::stream::FuturesUnordered;
-use solana_client::client_error::ClientError;
-use solana_client::client_error::ClientErrorKind;
-use solana_client::rpc_client::RpcClient;
 use solana_client::rpc_response::RpcVoteAccountStatus;
 use solana_lite_rpc_core::stores::data_cache::DataCache;
 use solana_lite_rpc_core::structures::leaderschedule::CalculatedSchedule;
 use solana_lite_rpc_core::structures::leaderschedule::LeaderScheduleData;
-use solana_program::slot_history::Slot;
+use solana_rpc_client::nonblocking::rpc_client::RpcClient;
 use solana_sdk::account::Account;
-use solana_sdk::commitment_config::CommitmentConfig;
-use solana_sdk::epoch_info::EpochInfo;
 use solana_sdk::pubkey::Pubkey;
 use solana_sdk::sysvar::epoch_schedule::EpochSchedule;
-use std::collections::HashMap;
+use std::sync::Arc;
 use std::time::Duration;
 use tokio::task::JoinHandle;
 
@@ -66,46 +61,36 @@ pub fn bootstrap_leaderschedule_from_files(
 }
 
 // Return the current or next epoch leader schedule using the RPC calls.
-pub fn bootstrap_leaderschedule_from_rpc(
-    rpc_url: String,
+pub async fn bootstrap_leaderschedule_from_rpc(
+    rpc_client: &RpcClient,
     epoch_schedule: &EpochSchedule,
-) -> Result<CalculatedSchedule, ClientError> {
-    let current_epoch = get_rpc_epoch_info(rpc_url.clone())?;
-    let current_schedule_by_node =
-        get_rpc_leader_schedule(rpc_url.clone(), None)?.ok_or(ClientError {
-            request: None,
-            kind: ClientErrorKind::Custom("RPC return no leader schedule".to_string()),
-        })?;
+) -> anyhow::Result<CalculatedSchedule> {
+    let current_epoch = rpc_client.get_epoch_info().await?;
+    let current_schedule_by_node = rpc_client
+        .get_leader_schedule(None)
+        .await?
+        .ok_or(anyhow!("RPC return no leader schedule"))?;
 
     //Calculate the slot leaders by from the node schedule because RPC call get_slot_leaders is limited to 5000 slots.
     let current_schedule_by_slot =
         crate::leader_schedule::calculate_slot_leaders_from_schedule(&current_schedule_by_node)
-            .map_err(|err| ClientError {
-                request: None,
-                kind: ClientErrorKind::Custom(format!(
-                    "Leader schedule from RPC can't generate slot leaders because:{err}"
-                )),
+            .map_err(|err| {
+                anyhow!("Leader schedule from RPC can't generate slot leaders because:{err}")
             })?;
 
     //get next epoch rpc schedule
     let next_epoch = current_epoch.epoch + 1;
     let next_first_epoch_slot = epoch_schedule.get_first_slot_in_epoch(next_epoch);
-    let next_schedule_by_node =
-        get_rpc_leader_schedule(rpc_url.clone(), Some(next_first_epoch_slot))?.ok_or(
-            ClientError {
-                request: None,
-                kind: ClientErrorKind::Custom("RPC return no leader schedule".to_string()),
-            },
-        )?;
+    let next_schedule_by_node = rpc_client
+        .get_leader_schedule(Some(next_first_epoch_slot))
+        .await?
+        .ok_or(anyhow!("RPC return no leader schedule"))?;
 
     //Calculate the slot leaders by from the node schedule because RPC call get_slot_leaders is limited to 5000 slots.
     let next_schedule_by_slot =
         crate::leader_schedule::calculate_slot_leaders_from_schedule(&next_schedule_by_node)
-            .map_err(|err| ClientError {
-                request: None,
-                kind: ClientErrorKind::Custom(format!(
-                    "Leader schedule from RPC can't generate slot leaders because:{err}"
-                )),
+            .map_err(|err| {
+                anyhow!("Leader schedule from RPC can't generate slot leaders because:{err}")
             })?;
 
     Ok(CalculatedSchedule {
@@ -185,13 +170,13 @@ pub fn run_bootstrap_events(
 pub enum BootstrapEvent {
     InitBootstrap {
         sleep_time: u64,
-        rpc_url: String,
+        rpc_client: Arc<RpcClient>,
     },
     BootstrapAccountsFetched(
         Vec<(Pubkey, Account)>,
         Vec<(Pubkey, Account)>,
         Account,
-        String,
+        Arc<RpcClient>,
     ),
     StoreExtracted(
         StakeMap,
@@ -200,13 +185,13 @@ pub enum BootstrapEvent {
         Vec<(Pubkey, Account)>,
         Vec<(Pubkey, Account)>,
         Account,
-        String,
+        Arc<RpcClient>,
     ),
     AccountsMerged(
         StakeMap,
         VoteMap,
         EpochVoteStakesCache,
-        String,
+        Arc<RpcClient>,
         anyhow::Result<(CalculatedSchedule, RpcVoteAccountStatus)>,
     ),
     Exit,
@@ -230,15 +215,15 @@ fn process_bootstrap_event(
     match event {
         BootstrapEvent::InitBootstrap {
             sleep_time,
-            rpc_url,
+            rpc_client,
         } => {
-            let jh = tokio::task::spawn_blocking(move || {
+            let jh = tokio::spawn(async move {
                 if sleep_time > 0 {
-                    std::thread::sleep(Duration::from_secs(sleep_time));
+                    tokio::time::sleep(Duration::from_secs(sleep_time)).await;
                 }
-                match bootstrap_accounts(rpc_url.clone()) {
+                match bootstrap_accounts(&rpc_client).await {
                     Ok((stakes, votes, history)) => {
-                        BootstrapEvent::BootstrapAccountsFetched(stakes, votes, history, rpc_url)
+                        BootstrapEvent::BootstrapAccountsFetched(stakes, votes, history, rpc_client)
                     }
                     Err(err) => {
                         log::warn!(
@@ -250,7 +235,7 @@ fn process_bootstrap_event(
             });
             BootsrapProcessResult::TaskHandle(jh)
         }
-        BootstrapEvent::BootstrapAccountsFetched(stakes, votes, history, rpc_url) => {
+        BootstrapEvent::BootstrapAccountsFetched(stakes, votes, history, rpc_client) => {
             match (&mut stakestore.stakes, &mut votestore.votes).take() {
                 TakeResult::Map((stake_map, (vote_map, epoch_cache))) => {
                     BootsrapProcessResult::Event(BootstrapEvent::StoreExtracted(
@@ -260,7 +245,7 @@ fn process_bootstrap_event(
                         stakes,
                         votes,
                         history,
-                        rpc_url,
+                        rpc_client,
                     ))
                 }
                 TakeResult::Taken(stake_notify) => {
@@ -272,7 +257,7 @@ fn process_bootstrap_event(
                                 .collect::<Vec<tokio::sync::futures::Notified>>();
                             join_all(notifs).await;
                             BootstrapEvent::BootstrapAccountsFetched(
-                                stakes, votes, history, rpc_url,
+                                stakes, votes, history, rpc_client,
                             )
                         }
                     });
@@ -288,7 +273,7 @@ fn process_bootstrap_event(
             stakes,
             votes,
             history,
-            rpc_url,
+            rpc_client,
         ) => {
             let stake_history = crate::account::read_historystake_from_account(&history.data);
             if stake_history.is_none() {
@@ -327,7 +312,7 @@ fn process_bootstrap_event(
                                 stake_map,
                                 vote_map,
                                 epoch_cache,
-                                rpc_url,
+                                rpc_client,
                                 Ok((leader_schedule, vote_acccounts)),
                             )
                         }
@@ -335,7 +320,7 @@ fn process_bootstrap_event(
                             stake_map,
                             vote_map,
                             epoch_cache,
-                            rpc_url,
+                            rpc_client,
                             Err(err),
                         ),
                     }
@@ -347,7 +332,7 @@ fn process_bootstrap_event(
             stake_map,
             vote_map,
             epoch_cache,
-            rpc_url,
+            rpc_client,
             leader_schedule_result,
         ) => {
             match (
@@ -360,7 +345,7 @@ fn process_bootstrap_event(
                     log::warn!("BootstrapEvent::AccountsMerged merge stake or vote fail,  non extracted stake/vote map err, restart bootstrap");
                     BootsrapProcessResult::Event(BootstrapEvent::InitBootstrap {
                         sleep_time: 10,
-                        rpc_url,
+                        rpc_client,
                     })
                 }
             }
@@ -369,69 +354,21 @@ fn process_bootstrap_event(
     }
 }
 
+// the stake, vote and stake history accounts at the finalized commitment of the client
 #[allow(clippy::type_complexity)]
-fn bootstrap_accounts(
-    rpc_url: String,
-) -> Result<(Vec<(Pubkey, Account)>, Vec<(Pubkey, Account)>, Account), ClientError> {
-    get_stake_account(rpc_url)
-        .and_then(|(stakes, rpc_url)| {
-            get_vote_account(rpc_url).map(|(votes, rpc_url)| (stakes, votes, rpc_url))
-        })
-        .and_then(|(stakes, votes, rpc_url)| {
-            get_stakehistory_account(rpc_url).map(|history| (stakes, votes, history))
-        })
-}
-
-fn get_stake_account(rpc_url: String) -> Result<(Vec<(Pubkey, Account)>, String), ClientError> {
-    let rpc_client = RpcClient::new_with_timeout_and_commitment(
-        rpc_url.clone(),
-        Duration::from_secs(600),
-        CommitmentConfig::finalized(),
-    );
-    rpc_client
+async fn bootstrap_accounts(
+    rpc_client: &RpcClient,
+) -> anyhow::Result<(Vec<(Pubkey, Account)>, Vec<(Pubkey, Account)>, Account)> {
+    let stakes = rpc_client
         .get_program_accounts(&solana_sdk::stake::program::id())
-        .map(|stake| (stake, rpc_url))
-}
-
-fn get_vote_account(rpc_url: String) -> Result<(Vec<(Pubkey, Account)>, String), ClientError> {
-    let rpc_client = RpcClient::new_with_timeout_and_commitment(
-        rpc_url.clone(),
-        Duration::from_secs(600),
-        CommitmentConfig::finalized(),
-    );
-    rpc_client
+        .await?;
+    let votes = rpc_client
         .get_program_accounts(&solana_sdk::vote::program::id())
-        .map(|votes| (votes, rpc_url))
-}
-
-pub fn get_stakehistory_account(rpc_url: String) -> Result<Account, ClientError> {
-    let rpc_client = RpcClient::new_with_timeout_and_commitment(
-        rpc_url,
-        Duration::from_secs(600),
-        CommitmentConfig::finalized(),
-    );
-    rpc_client.get_account(&solana_sdk::sysvar::stake_history::id())
-}
-
-fn get_rpc_epoch_info(rpc_url: String) -> Result<EpochInfo, ClientError> {
-    let rpc_client = RpcClient::new_with_timeout_and_commitment(
-        rpc_url.clone(),
-        Duration::from_secs(600),
-        CommitmentConfig::finalized(),
-    );
-    rpc_client.get_epoch_info()
-}
-
-fn get_rpc_leader_schedule(
-    rpc_url: String,
-    slot: Option<Slot>,
-) -> Result<Option<HashMap<String, Vec<usize>>>, ClientError> {
-    let rpc_client = RpcClient::new_with_timeout_and_commitment(
-        rpc_url.clone(),
-        Duration::from_secs(600),
-        CommitmentConfig::finalized(),
-    );
-    rpc_client.get_leader_schedule(slot)
+        .await?;
+    let history = rpc_client
+        .get_account(&solana_sdk::sysvar::stake_history::id())
+        .await?;
+    Ok((stakes, votes, history))
 }
 
 // pub struct BootstrapScheduleResult {
@@ -450,14 +387,7 @@ pub fn bootstrap_current_leader_schedule(
 
     //verify that the current loaded epoch correspond to the current epoch slot
     if current_epoch_of_loading != current_epoch {
-        return Err(ClientError {
-            request: None,
-            kind: ClientErrorKind::Custom(
-                "Current epoch bootstrap file doesn't correspond to the validator current epoch."
-                    .to_string(),
-            ),
-        }
-        .into());
+        bail!("Current epoch bootstrap file doesn't correspond to the validator current epoch.");
     }
 
     //calcualte leader schedule for all vote stakes.
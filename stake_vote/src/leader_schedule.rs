@@ -7,8 +7,8 @@ use crate::vote::{VoteMap, VoteStore};
 use futures::future::join_all;
 use futures::stream::FuturesUnordered;
 use itertools::Itertools;
-use serde::{Deserialize, Serialize};
-use solana_ledger::leader_schedule::LeaderSchedule;
+use rand::distributions::{Distribution, WeightedIndex};
+use rand_chacha::{rand_core::SeedableRng, ChaChaRng};
 use solana_lite_rpc_core::structures::leaderschedule::LeaderScheduleData;
 use solana_sdk::clock::NUM_CONSECUTIVE_LEADER_SLOTS;
 use solana_sdk::pubkey::Pubkey;
@@ -21,7 +21,6 @@ use tokio::task::JoinHandle;
 
 #[derive(Debug)]
 pub struct LeaderScheduleGeneratedData {
-    pub schedule: LeaderSchedule,
     pub rpc_data: LeaderScheduleData,
     pub epoch: u64,
 }
@@ -39,12 +38,6 @@ impl LeaderScheduleGeneratedData {
     }
 }
 
-#[derive(Debug, Serialize, Deserialize)]
-pub struct EpochStake {
-    epoch: u64,
-    stake_vote_map: HashMap<Pubkey, (u64, Arc<StoredVote>)>,
-}
-
 /*
 Leader schedule calculus state diagram
 
@@ -184,7 +177,6 @@ fn process_leadershedule_event(
                                 vote_map,
                                 epoch_cache,
                                 LeaderScheduleGeneratedData {
-                                    schedule: leader_schedule,
                                     rpc_data,
                                     epoch: next_epoch,
                                 },
@@ -336,3 +328,32 @@ fn sort_stakes(stakes: &mut Vec<(Pubkey, u64)>) {
     // Now that it's sorted, we can do an O(n) dedup.
     stakes.dedup();
 }
+
+// Copied from solana_ledger leader_schedule.rs, only the slot leaders are kept
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct LeaderSchedule {
+    slot_leaders: Vec<Pubkey>,
+}
+
+impl LeaderSchedule {
+    // Note: passing in zero stakers will cause a panic.
+    pub fn new(ids_and_stakes: &[(Pubkey, u64)], seed: [u8; 32], len: u64, repeat: u64) -> Self {
+        let (ids, stakes): (Vec<_>, Vec<_>) = ids_and_stakes.iter().cloned().unzip();
+        let rng = &mut ChaChaRng::from_seed(seed);
+        let weighted_index = WeightedIndex::new(stakes).unwrap();
+        let mut current_node = Pubkey::default();
+        let slot_leaders = (0..len)
+            .map(|i| {
+                if i % repeat == 0 {
+                    current_node = ids[weighted_index.sample(rng)];
+                }
+                current_node
+            })
+            .collect();
+        Self { slot_leaders }
+    }
+
+    pub fn get_slot_leaders(&self) -> &[Pubkey] {
+        &self.slot_leaders
+    }
+}
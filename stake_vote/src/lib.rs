@@ -38,7 +38,7 @@ const VOTESTORE_INITIAL_CAPACITY: usize = 600000;
 type Slot = u64;
 
 pub async fn bootstrat_literpc_leader_schedule(
-    rpc_url: String,
+    rpc_client: &RpcClient,
     data_cache: &DataCache,
     current_epoch_of_loading: u64,
 ) {
@@ -60,9 +60,11 @@ pub async fn bootstrat_literpc_leader_schedule(
         None => {
             log::info!("Leader schedule bootstrap file not found. Try to boot from rpc.");
             match crate::bootstrap::bootstrap_leaderschedule_from_rpc(
-                rpc_url,
+                rpc_client,
                 data_cache.epoch_data.get_epoch_schedule(),
-            ) {
+            )
+            .await
+            {
                 Ok(leader_schedule) => {
                     log::info!("Leader schedule bootstrap from rpc done.",);
                     let mut data_schedule = data_cache.leader_schedule.write().await;
@@ -110,7 +112,7 @@ pub async fn start_stakes_and_votes_loop(
         let jh = tokio::spawn(async move {
             BootstrapEvent::InitBootstrap {
                 sleep_time: 1,
-                rpc_url: rpc_client.url(),
+                rpc_client,
             }
         });
         spawned_bootstrap_task.push(jh);
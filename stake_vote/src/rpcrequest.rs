@@ -5,6 +5,7 @@ use crate::vote::VoteMap;
 use crate::vote::VoteStore;
 use crate::Slot;
 use futures_util::stream::FuturesUnordered;
+use solana_lite_rpc_core::stores::vote_account_store::filter_vote_accounts;
 use solana_lite_rpc_core::structures::leaderschedule::GetVoteAccountsConfig;
 use solana_rpc_client_api::response::RpcVoteAccountStatus;
 use tokio::sync::oneshot;
@@ -14,7 +15,14 @@ pub struct RpcRequestData {
     pub rpc_notify_task: FuturesUnordered<JoinHandle<(u64, u64, GetVoteAccountsConfig)>>,
     pub rpc_exec_task:
         FuturesUnordered<JoinHandle<(VoteMap, EpochVoteStakesCache, RpcVoteAccountStatus)>>,
-    pending_rpc_request: Option<Vec<oneshot::Sender<RpcVoteAccountStatus>>>,
+    // each request is answered with its own parameters applied
+    pending_rpc_request: Option<
+        Vec<(
+            Slot,
+            GetVoteAccountsConfig,
+            oneshot::Sender<RpcVoteAccountStatus>,
+        )>,
+    >,
 }
 
 impl RpcRequestData {
@@ -34,10 +42,11 @@ impl RpcRequestData {
         return_channel: oneshot::Sender<RpcVoteAccountStatus>,
         votestore: &mut VoteStore,
     ) {
+        let pending_request = (current_slot, config.clone(), return_channel);
         match self.pending_rpc_request {
-            Some(ref mut pending) => pending.push(return_channel),
+            Some(ref mut pending) => pending.push(pending_request),
             None => {
-                self.pending_rpc_request = Some(vec![return_channel]);
+                self.pending_rpc_request = Some(vec![pending_request]);
             }
         }
         self.take_vote_accounts_and_process(votestore, current_slot, epoch, config)
@@ -53,23 +62,14 @@ impl RpcRequestData {
         if let Err(err) = votestore.votes.merge((votes, vote_accounts)) {
             log::error!("Error during  RPC get vote account merge:{err}");
         }
-        //avoid clone on the first request
-        if let Some(mut pending_rpc_request) = self.pending_rpc_request.take() {
-            if pending_rpc_request.len() > 1 {
-                for return_channel in pending_rpc_request.drain(0..pending_rpc_request.len() - 1) {
-                    if return_channel.send(rpc_vote_accounts.clone()).is_err() {
-                        log::error!("Vote accounts RPC channel send closed.");
-                    }
+        if let Some(pending_rpc_request) = self.pending_rpc_request.take() {
+            for (current_slot, config, return_channel) in pending_rpc_request {
+                let vote_accounts =
+                    filter_vote_accounts(rpc_vote_accounts.clone(), current_slot, &config);
+                if return_channel.send(vote_accounts).is_err() {
+                    log::error!("Vote accounts RPC channel send closed.");
                 }
             }
-            if pending_rpc_request
-                .pop()
-                .unwrap()
-                .send(rpc_vote_accounts)
-                .is_err()
-            {
-                log::error!("Vote accounts RPC channel send closed.");
-            }
         }
     }
 
@@ -93,11 +93,15 @@ impl RpcRequestData {
             let jh = tokio::task::spawn_blocking({
                 move || match vote_accounts.vote_stakes_for_epoch(epoch) {
                     Some(stakes) => {
+                        // unfiltered, the parameters of the pending requests are applied on notification
                         let rpc_vote_accounts = crate::vote::get_rpc_vote_accounts_info(
                             current_slot,
                             &votes,
                             &stakes.vote_stakes,
-                            config,
+                            GetVoteAccountsConfig {
+                                keep_unstaked_delinquents: Some(true),
+                                ..config
+                            },
                         );
                         (votes, vote_accounts, rpc_vote_accounts)
                     }
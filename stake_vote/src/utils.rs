@@ -69,6 +69,7 @@ pub fn save_schedule_vote_stakes(
 }
 
 #[derive(Debug)]
+#[allow(dead_code)]
 pub enum UpdateAction<Account> {
     Notify(Slot, Account),
     Remove(Pubkey, Slot),
@@ -107,7 +108,7 @@ pub trait Takable<C> {
     fn is_taken(&self) -> bool;
 }
 
-impl<'a, T, C: TakableContent<T>> Takable<C> for &'a mut TakableMap<T, C> {
+impl<T, C: TakableContent<T>> Takable<C> for &mut TakableMap<T, C> {
     fn take(self) -> TakeResult<C> {
         match self.content.take() {
             Some(content) => TakeResult::Map(content),
@@ -233,17 +234,17 @@ impl<T: Default, C: TakableContent<T> + Default> TakableMap<T, C> {
 mod tests {
     use super::*;
 
-    #[tokio::test]
-    async fn test_takable_struct() {
-        impl TakableContent<u64> for Vec<u64> {
-            fn add_value(&mut self, val: UpdateAction<u64>) {
-                match val {
-                    UpdateAction::Notify(account, _) => self.push(account),
-                    UpdateAction::Remove(_, _) => (),
-                }
+    impl TakableContent<u64> for Vec<u64> {
+        fn add_value(&mut self, val: UpdateAction<u64>) {
+            match val {
+                UpdateAction::Notify(account, _) => self.push(account),
+                UpdateAction::Remove(_, _) => (),
             }
         }
+    }
 
+    #[tokio::test]
+    async fn test_takable_struct() {
         let content: Vec<u64> = vec![];
         let mut takable = TakableMap::new(content);
         takable.add_value(UpdateAction::Notify(23, 0), false);